@@ -4,6 +4,9 @@ use std::{env, path::PathBuf};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    // The protos live outside this package, so cargo won't notice edits on its own
+    println!("cargo:rerun-if-changed=../proto");
+    println!("cargo:rerun-if-changed=build.rs");
     //let folder_path = Path::new(&std::env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("../proto");
 
     tonic_prost_build::configure()
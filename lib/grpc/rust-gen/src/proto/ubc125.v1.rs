@@ -1,10 +1,43 @@
 // This file is @generated by prost-build.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct GetModelInfoRequest {}
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetModelInfoResponse {
+    /// The MDL reply, e.g. "MDL,BC125AT"
     #[prost(string, tag = "1")]
     pub result: ::prost::alloc::string::String,
+    /// The model name, e.g. "BC125AT"
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    /// False for a model we have no capability table for, in which case only
+    /// Uniden's published commands are used
+    #[prost(bool, tag = "3")]
+    pub known: bool,
+    #[prost(message, optional, tag = "4")]
+    pub capabilities: ::core::option::Option<ModelCapabilities>,
+}
+/// A range of frequencies, both ends included.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Band {
+    #[prost(message, optional, tag = "1")]
+    pub lower: ::core::option::Option<Frequency>,
+    #[prost(message, optional, tag = "2")]
+    pub upper: ::core::option::Option<Frequency>,
+    /// Channel steps used in the band, e.g. "8.33 kHz"
+    #[prost(string, repeated, tag = "3")]
+    pub steps: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ModelCapabilities {
+    #[prost(uint32, tag = "1")]
+    pub channel_count: u32,
+    #[prost(uint32, tag = "2")]
+    pub bank_count: u32,
+    #[prost(message, repeated, tag = "3")]
+    pub bands: ::prost::alloc::vec::Vec<Band>,
+    /// Undocumented commands the model answers, e.g. "GLG"
+    #[prost(string, repeated, tag = "5")]
+    pub extra_commands: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct GetFirmwareVersionRequest {}
@@ -14,13 +47,22 @@ pub struct GetFirmwareVersionResponse {
     pub result: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct WatchConnectionRequest {}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct WatchConnectionResponse {
+    #[prost(enumeration = "ConnectionState", tag = "1")]
+    pub state: i32,
+    #[prost(uint32, tag = "2")]
+    pub reconnect_attempts: u32,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct GetAudioSettingsRequest {}
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct GetAudioSettingsResponse {
-    #[prost(string, tag = "1")]
-    pub volume: ::prost::alloc::string::String,
-    #[prost(string, tag = "2")]
-    pub squelch: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "1")]
+    pub volume: u32,
+    #[prost(uint32, tag = "2")]
+    pub squelch: u32,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct StartScanRequest {}
@@ -44,31 +86,106 @@ pub struct SetEnabledBanksRequest {
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct SetEnabledBanksResponse {}
+/// A radio frequency. The scanner resolves 100 Hz steps; hz that isn't a
+/// multiple of 100 is rejected with INVALID_ARGUMENT.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Frequency {
+    #[prost(uint32, tag = "1")]
+    pub hz: u32,
+}
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct GetStatusRequest {}
+/// Frequency is unset, and the other fields are defaults, while the scanner
+/// is between channels.
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct GetStatusResponse {
-    #[prost(string, tag = "1")]
-    pub frequency: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "6")]
+    pub frequency: ::core::option::Option<Frequency>,
+    #[prost(enumeration = "Modulation", tag = "7")]
+    pub modulation: i32,
+    #[prost(bool, tag = "8")]
+    pub attenuator: bool,
+    /// GLG's Signal Status field, whose values aren't documented
+    #[prost(uint32, tag = "12")]
+    pub signal_status: u32,
     #[prost(string, tag = "2")]
     pub bank: ::prost::alloc::string::String,
     #[prost(string, tag = "3")]
     pub channel_name: ::prost::alloc::string::String,
+    /// Squelch open
     #[prost(bool, tag = "4")]
     pub signal_detected: bool,
+    #[prost(bool, tag = "10")]
+    pub muted: bool,
+    /// 0 for a search or Close Call hit
+    #[prost(uint32, tag = "11")]
+    pub channel_index: u32,
     #[prost(string, tag = "5")]
     pub raw_response: ::prost::alloc::string::String,
 }
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetDisplayRequest {}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DisplayLine {
+    #[prost(string, tag = "1")]
+    pub text: ::prost::alloc::string::String,
+    /// One per character of text
+    #[prost(enumeration = "CharStyle", repeated, tag = "2")]
+    pub styles: ::prost::alloc::vec::Vec<i32>,
+    #[prost(bool, tag = "3")]
+    pub large: bool,
+}
+/// What the radio's LCD shows, menus included.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetDisplayResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub lines: ::prost::alloc::vec::Vec<DisplayLine>,
+    #[prost(bool, tag = "2")]
+    pub squelch_open: bool,
+    #[prost(bool, tag = "3")]
+    pub muted: bool,
+    #[prost(bool, tag = "4")]
+    pub battery_low: bool,
+    /// Bars, 0-5
+    #[prost(uint32, tag = "5")]
+    pub signal_level: u32,
+}
+/// The tone squelch setting of a channel.
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct Tone {
+    #[prost(enumeration = "ToneKind", tag = "1")]
+    pub kind: i32,
+    /// CTCSS tone in tenths of Hz, e.g. 1035 for 103.5 Hz
+    #[prost(uint32, tag = "2")]
+    pub ctcss_decihertz: u32,
+    /// DCS code, e.g. "023"
+    #[prost(string, tag = "3")]
+    pub dcs_code: ::prost::alloc::string::String,
+    /// How the scanner shows it, e.g. "CTCSS 103.5". Read only.
+    #[prost(string, tag = "4")]
+    pub name: ::prost::alloc::string::String,
+}
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct Channel {
     #[prost(uint32, tag = "1")]
     pub index: u32,
+    /// Up to 16 printable ASCII characters, without commas. Accented letters
+    /// are written without their accents.
     #[prost(string, tag = "2")]
     pub name: ::prost::alloc::string::String,
-    #[prost(string, tag = "3")]
-    pub frequency: ::prost::alloc::string::String,
-    #[prost(string, tag = "4")]
-    pub modulation: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "5")]
+    pub frequency: ::core::option::Option<Frequency>,
+    #[prost(enumeration = "Modulation", tag = "6")]
+    pub modulation: i32,
+    #[prost(message, optional, tag = "11")]
+    pub tone: ::core::option::Option<Tone>,
+    /// Seconds: -10, -5 or 0-5
+    #[prost(int32, tag = "8")]
+    pub delay: i32,
+    #[prost(bool, tag = "9")]
+    pub lockout: bool,
+    #[prost(bool, tag = "10")]
+    pub priority: bool,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct GetChannelRequest {
@@ -94,6 +211,486 @@ pub struct DeleteChannelRequest {
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct DeleteChannelResponse {}
+/// A custom search range (1-10).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SearchRange {
+    #[prost(uint32, tag = "1")]
+    pub index: u32,
+    #[prost(message, optional, tag = "2")]
+    pub lower: ::core::option::Option<Frequency>,
+    #[prost(message, optional, tag = "3")]
+    pub upper: ::core::option::Option<Frequency>,
+    /// Included in a custom search
+    #[prost(bool, tag = "4")]
+    pub enabled: bool,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListSearchRangesRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListSearchRangesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub ranges: ::prost::alloc::vec::Vec<SearchRange>,
+}
+/// Writes the limits and whether the range is enabled.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetSearchRangeRequest {
+    #[prost(message, optional, tag = "1")]
+    pub range: ::core::option::Option<SearchRange>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetSearchRangeResponse {}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetEnabledSearchRangesRequest {
+    /// Range 1 first, 10 entries
+    #[prost(bool, repeated, tag = "1")]
+    pub ranges: ::prost::alloc::vec::Vec<bool>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetEnabledSearchRangesResponse {}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct StartCustomSearchRequest {}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct StartCustomSearchResponse {}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CloseCallSettings {
+    #[prost(enumeration = "CloseCallMode", tag = "1")]
+    pub mode: i32,
+    #[prost(bool, tag = "2")]
+    pub alert_beep: bool,
+    #[prost(bool, tag = "3")]
+    pub alert_light: bool,
+    /// 25-54, 108-137, 137-174, 225-380 and 400-512 MHz, 5 entries
+    #[prost(bool, repeated, tag = "4")]
+    pub bands: ::prost::alloc::vec::Vec<bool>,
+    #[prost(bool, tag = "5")]
+    pub lockout: bool,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetCloseCallSettingsRequest {}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetCloseCallSettingsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub settings: ::core::option::Option<CloseCallSettings>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetCloseCallSettingsRequest {
+    #[prost(message, optional, tag = "1")]
+    pub settings: ::core::option::Option<CloseCallSettings>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetCloseCallSettingsResponse {}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct WatchCloseCallHitsRequest {}
+/// Sent once when a Close Call hit starts.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct WatchCloseCallHitsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub frequency: ::core::option::Option<Frequency>,
+    #[prost(enumeration = "Modulation", tag = "2")]
+    pub modulation: i32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ServiceGroupState {
+    #[prost(enumeration = "ServiceGroup", tag = "1")]
+    pub group: i32,
+    /// How the radio names it, e.g. "Fire/Emergency"
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    /// Included in a service search
+    #[prost(bool, tag = "3")]
+    pub enabled: bool,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListServiceGroupsRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListServiceGroupsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub groups: ::prost::alloc::vec::Vec<ServiceGroupState>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetServiceGroupEnabledRequest {
+    #[prost(enumeration = "ServiceGroup", tag = "1")]
+    pub group: i32,
+    #[prost(bool, tag = "2")]
+    pub enabled: bool,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetServiceGroupEnabledResponse {}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct StartServiceSearchRequest {}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct StartServiceSearchResponse {}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct KeyPress {
+    #[prost(enumeration = "Key", tag = "1")]
+    pub key: i32,
+    #[prost(enumeration = "KeyAction", tag = "2")]
+    pub action: i32,
+}
+/// Presses the keys in order, stopping at the first the radio refuses.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PressKeysRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub keys: ::prost::alloc::vec::Vec<KeyPress>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PressKeysResponse {}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ConnectionState {
+    Unspecified = 0,
+    Connected = 1,
+    Disconnected = 2,
+}
+impl ConnectionState {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "CONNECTION_STATE_UNSPECIFIED",
+            Self::Connected => "CONNECTION_STATE_CONNECTED",
+            Self::Disconnected => "CONNECTION_STATE_DISCONNECTED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CONNECTION_STATE_UNSPECIFIED" => Some(Self::Unspecified),
+            "CONNECTION_STATE_CONNECTED" => Some(Self::Connected),
+            "CONNECTION_STATE_DISCONNECTED" => Some(Self::Disconnected),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CharStyle {
+    Unspecified = 0,
+    Normal = 1,
+    Reverse = 2,
+    Underline = 3,
+}
+impl CharStyle {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "CHAR_STYLE_UNSPECIFIED",
+            Self::Normal => "CHAR_STYLE_NORMAL",
+            Self::Reverse => "CHAR_STYLE_REVERSE",
+            Self::Underline => "CHAR_STYLE_UNDERLINE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CHAR_STYLE_UNSPECIFIED" => Some(Self::Unspecified),
+            "CHAR_STYLE_NORMAL" => Some(Self::Normal),
+            "CHAR_STYLE_REVERSE" => Some(Self::Reverse),
+            "CHAR_STYLE_UNDERLINE" => Some(Self::Underline),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Modulation {
+    Unspecified = 0,
+    Auto = 1,
+    Am = 2,
+    Fm = 3,
+    Nfm = 4,
+}
+impl Modulation {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "MODULATION_UNSPECIFIED",
+            Self::Auto => "MODULATION_AUTO",
+            Self::Am => "MODULATION_AM",
+            Self::Fm => "MODULATION_FM",
+            Self::Nfm => "MODULATION_NFM",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "MODULATION_UNSPECIFIED" => Some(Self::Unspecified),
+            "MODULATION_AUTO" => Some(Self::Auto),
+            "MODULATION_AM" => Some(Self::Am),
+            "MODULATION_FM" => Some(Self::Fm),
+            "MODULATION_NFM" => Some(Self::Nfm),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ToneKind {
+    Unspecified = 0,
+    None = 1,
+    Ctcss = 2,
+    Dcs = 3,
+    Search = 4,
+    NoTone = 5,
+}
+impl ToneKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "TONE_KIND_UNSPECIFIED",
+            Self::None => "TONE_KIND_NONE",
+            Self::Ctcss => "TONE_KIND_CTCSS",
+            Self::Dcs => "TONE_KIND_DCS",
+            Self::Search => "TONE_KIND_SEARCH",
+            Self::NoTone => "TONE_KIND_NO_TONE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TONE_KIND_UNSPECIFIED" => Some(Self::Unspecified),
+            "TONE_KIND_NONE" => Some(Self::None),
+            "TONE_KIND_CTCSS" => Some(Self::Ctcss),
+            "TONE_KIND_DCS" => Some(Self::Dcs),
+            "TONE_KIND_SEARCH" => Some(Self::Search),
+            "TONE_KIND_NO_TONE" => Some(Self::NoTone),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CloseCallMode {
+    Unspecified = 0,
+    Off = 1,
+    Priority = 2,
+    DoNotDisturb = 3,
+}
+impl CloseCallMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "CLOSE_CALL_MODE_UNSPECIFIED",
+            Self::Off => "CLOSE_CALL_MODE_OFF",
+            Self::Priority => "CLOSE_CALL_MODE_PRIORITY",
+            Self::DoNotDisturb => "CLOSE_CALL_MODE_DO_NOT_DISTURB",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CLOSE_CALL_MODE_UNSPECIFIED" => Some(Self::Unspecified),
+            "CLOSE_CALL_MODE_OFF" => Some(Self::Off),
+            "CLOSE_CALL_MODE_PRIORITY" => Some(Self::Priority),
+            "CLOSE_CALL_MODE_DO_NOT_DISTURB" => Some(Self::DoNotDisturb),
+            _ => None,
+        }
+    }
+}
+/// The radio's built-in service searches.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ServiceGroup {
+    Unspecified = 0,
+    Police = 1,
+    FireEmergency = 2,
+    Ham = 3,
+    Marine = 4,
+    Railroad = 5,
+    Air = 6,
+    Cb = 7,
+    FrsGmrs = 8,
+    Racing = 9,
+    Special = 10,
+}
+impl ServiceGroup {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SERVICE_GROUP_UNSPECIFIED",
+            Self::Police => "SERVICE_GROUP_POLICE",
+            Self::FireEmergency => "SERVICE_GROUP_FIRE_EMERGENCY",
+            Self::Ham => "SERVICE_GROUP_HAM",
+            Self::Marine => "SERVICE_GROUP_MARINE",
+            Self::Railroad => "SERVICE_GROUP_RAILROAD",
+            Self::Air => "SERVICE_GROUP_AIR",
+            Self::Cb => "SERVICE_GROUP_CB",
+            Self::FrsGmrs => "SERVICE_GROUP_FRS_GMRS",
+            Self::Racing => "SERVICE_GROUP_RACING",
+            Self::Special => "SERVICE_GROUP_SPECIAL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SERVICE_GROUP_UNSPECIFIED" => Some(Self::Unspecified),
+            "SERVICE_GROUP_POLICE" => Some(Self::Police),
+            "SERVICE_GROUP_FIRE_EMERGENCY" => Some(Self::FireEmergency),
+            "SERVICE_GROUP_HAM" => Some(Self::Ham),
+            "SERVICE_GROUP_MARINE" => Some(Self::Marine),
+            "SERVICE_GROUP_RAILROAD" => Some(Self::Railroad),
+            "SERVICE_GROUP_AIR" => Some(Self::Air),
+            "SERVICE_GROUP_CB" => Some(Self::Cb),
+            "SERVICE_GROUP_FRS_GMRS" => Some(Self::FrsGmrs),
+            "SERVICE_GROUP_RACING" => Some(Self::Racing),
+            "SERVICE_GROUP_SPECIAL" => Some(Self::Special),
+            _ => None,
+        }
+    }
+}
+/// A button on the scanner.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Key {
+    Unspecified = 0,
+    Menu = 1,
+    Func = 2,
+    Hold = 3,
+    Scan = 4,
+    /// Srch/Close Call
+    Search = 5,
+    Lockout = 6,
+    Priority = 7,
+    Weather = 8,
+    Digit0 = 9,
+    Digit1 = 10,
+    Digit2 = 11,
+    Digit3 = 12,
+    Digit4 = 13,
+    Digit5 = 14,
+    Digit6 = 15,
+    Digit7 = 16,
+    Digit8 = 17,
+    Digit9 = 18,
+    /// ./No
+    Decimal = 19,
+    /// E/Yes
+    Enter = 20,
+    /// The scroll control turned anticlockwise
+    Left = 21,
+    /// The scroll control turned clockwise
+    Right = 22,
+    /// The scroll control pressed
+    Push = 23,
+}
+impl Key {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "KEY_UNSPECIFIED",
+            Self::Menu => "KEY_MENU",
+            Self::Func => "KEY_FUNC",
+            Self::Hold => "KEY_HOLD",
+            Self::Scan => "KEY_SCAN",
+            Self::Search => "KEY_SEARCH",
+            Self::Lockout => "KEY_LOCKOUT",
+            Self::Priority => "KEY_PRIORITY",
+            Self::Weather => "KEY_WEATHER",
+            Self::Digit0 => "KEY_DIGIT_0",
+            Self::Digit1 => "KEY_DIGIT_1",
+            Self::Digit2 => "KEY_DIGIT_2",
+            Self::Digit3 => "KEY_DIGIT_3",
+            Self::Digit4 => "KEY_DIGIT_4",
+            Self::Digit5 => "KEY_DIGIT_5",
+            Self::Digit6 => "KEY_DIGIT_6",
+            Self::Digit7 => "KEY_DIGIT_7",
+            Self::Digit8 => "KEY_DIGIT_8",
+            Self::Digit9 => "KEY_DIGIT_9",
+            Self::Decimal => "KEY_DECIMAL",
+            Self::Enter => "KEY_ENTER",
+            Self::Left => "KEY_LEFT",
+            Self::Right => "KEY_RIGHT",
+            Self::Push => "KEY_PUSH",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "KEY_UNSPECIFIED" => Some(Self::Unspecified),
+            "KEY_MENU" => Some(Self::Menu),
+            "KEY_FUNC" => Some(Self::Func),
+            "KEY_HOLD" => Some(Self::Hold),
+            "KEY_SCAN" => Some(Self::Scan),
+            "KEY_SEARCH" => Some(Self::Search),
+            "KEY_LOCKOUT" => Some(Self::Lockout),
+            "KEY_PRIORITY" => Some(Self::Priority),
+            "KEY_WEATHER" => Some(Self::Weather),
+            "KEY_DIGIT_0" => Some(Self::Digit0),
+            "KEY_DIGIT_1" => Some(Self::Digit1),
+            "KEY_DIGIT_2" => Some(Self::Digit2),
+            "KEY_DIGIT_3" => Some(Self::Digit3),
+            "KEY_DIGIT_4" => Some(Self::Digit4),
+            "KEY_DIGIT_5" => Some(Self::Digit5),
+            "KEY_DIGIT_6" => Some(Self::Digit6),
+            "KEY_DIGIT_7" => Some(Self::Digit7),
+            "KEY_DIGIT_8" => Some(Self::Digit8),
+            "KEY_DIGIT_9" => Some(Self::Digit9),
+            "KEY_DECIMAL" => Some(Self::Decimal),
+            "KEY_ENTER" => Some(Self::Enter),
+            "KEY_LEFT" => Some(Self::Left),
+            "KEY_RIGHT" => Some(Self::Right),
+            "KEY_PUSH" => Some(Self::Push),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum KeyAction {
+    /// Treated as a press
+    Unspecified = 0,
+    Press = 1,
+    LongPress = 2,
+    /// Held down until released
+    Hold = 3,
+    Release = 4,
+}
+impl KeyAction {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "KEY_ACTION_UNSPECIFIED",
+            Self::Press => "KEY_ACTION_PRESS",
+            Self::LongPress => "KEY_ACTION_LONG_PRESS",
+            Self::Hold => "KEY_ACTION_HOLD",
+            Self::Release => "KEY_ACTION_RELEASE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "KEY_ACTION_UNSPECIFIED" => Some(Self::Unspecified),
+            "KEY_ACTION_PRESS" => Some(Self::Press),
+            "KEY_ACTION_LONG_PRESS" => Some(Self::LongPress),
+            "KEY_ACTION_HOLD" => Some(Self::Hold),
+            "KEY_ACTION_RELEASE" => Some(Self::Release),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod system_info_service_client {
     #![allow(
@@ -235,6 +832,32 @@ pub mod system_info_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        pub async fn watch_connection(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WatchConnectionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::WatchConnectionResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/ubc125.v1.SystemInfoService/WatchConnection",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("ubc125.v1.SystemInfoService", "WatchConnection"),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -264,6 +887,19 @@ pub mod system_info_service_server {
             tonic::Response<super::GetFirmwareVersionResponse>,
             tonic::Status,
         >;
+        /// Server streaming response type for the WatchConnection method.
+        type WatchConnectionStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::WatchConnectionResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        async fn watch_connection(
+            &self,
+            request: tonic::Request<super::WatchConnectionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::WatchConnectionStream>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct SystemInfoServiceServer<T> {
@@ -436,6 +1072,54 @@ pub mod system_info_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/ubc125.v1.SystemInfoService/WatchConnection" => {
+                    #[allow(non_camel_case_types)]
+                    struct WatchConnectionSvc<T: SystemInfoService>(pub Arc<T>);
+                    impl<
+                        T: SystemInfoService,
+                    > tonic::server::ServerStreamingService<
+                        super::WatchConnectionRequest,
+                    > for WatchConnectionSvc<T> {
+                        type Response = super::WatchConnectionResponse;
+                        type ResponseStream = T::WatchConnectionStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WatchConnectionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SystemInfoService>::watch_connection(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = WatchConnectionSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(
@@ -720,6 +1404,32 @@ pub mod scanner_control_service_client {
                 .insert(GrpcMethod::new("ubc125.v1.ScannerControlService", "GetStatus"));
             self.inner.server_streaming(req, path, codec).await
         }
+        pub async fn get_display(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetDisplayRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::GetDisplayResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/ubc125.v1.ScannerControlService/GetDisplay",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("ubc125.v1.ScannerControlService", "GetDisplay"),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
         pub async fn get_channel(
             &mut self,
             request: impl tonic::IntoRequest<super::GetChannelRequest>,
@@ -798,184 +1508,1016 @@ pub mod scanner_control_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
-    }
-}
-/// Generated server implementations.
-pub mod scanner_control_service_server {
-    #![allow(
-        unused_variables,
-        dead_code,
-        missing_docs,
-        clippy::wildcard_imports,
-        clippy::let_unit_value,
-    )]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with ScannerControlServiceServer.
-    #[async_trait]
-    pub trait ScannerControlService: std::marker::Send + std::marker::Sync + 'static {
-        async fn get_audio_settings(
-            &self,
-            request: tonic::Request<super::GetAudioSettingsRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::GetAudioSettingsResponse>,
-            tonic::Status,
-        >;
-        async fn start_scan(
-            &self,
-            request: tonic::Request<super::StartScanRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::StartScanResponse>,
-            tonic::Status,
-        >;
-        async fn hold_scan(
-            &self,
-            request: tonic::Request<super::HoldScanRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::HoldScanResponse>,
-            tonic::Status,
-        >;
-        async fn get_enabled_banks(
-            &self,
-            request: tonic::Request<super::GetEnabledBanksRequest>,
+        pub async fn list_search_ranges(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListSearchRangesRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetEnabledBanksResponse>,
+            tonic::Response<super::ListSearchRangesResponse>,
             tonic::Status,
-        >;
-        async fn set_enabled_banks(
-            &self,
-            request: tonic::Request<super::SetEnabledBanksRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/ubc125.v1.ScannerControlService/ListSearchRanges",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "ubc125.v1.ScannerControlService",
+                        "ListSearchRanges",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_search_range(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetSearchRangeRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::SetEnabledBanksResponse>,
+            tonic::Response<super::SetSearchRangeResponse>,
             tonic::Status,
-        >;
-        /// Server streaming response type for the GetStatus method.
-        type GetStatusStream: tonic::codegen::tokio_stream::Stream<
-                Item = std::result::Result<super::GetStatusResponse, tonic::Status>,
-            >
-            + std::marker::Send
-            + 'static;
-        async fn get_status(
-            &self,
-            request: tonic::Request<super::GetStatusRequest>,
-        ) -> std::result::Result<tonic::Response<Self::GetStatusStream>, tonic::Status>;
-        async fn get_channel(
-            &self,
-            request: tonic::Request<super::GetChannelRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/ubc125.v1.ScannerControlService/SetSearchRange",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("ubc125.v1.ScannerControlService", "SetSearchRange"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_enabled_search_ranges(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetEnabledSearchRangesRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetChannelResponse>,
+            tonic::Response<super::SetEnabledSearchRangesResponse>,
             tonic::Status,
-        >;
-        async fn set_channel(
-            &self,
-            request: tonic::Request<super::SetChannelRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/ubc125.v1.ScannerControlService/SetEnabledSearchRanges",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "ubc125.v1.ScannerControlService",
+                        "SetEnabledSearchRanges",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn start_custom_search(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StartCustomSearchRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::SetChannelResponse>,
+            tonic::Response<super::StartCustomSearchResponse>,
             tonic::Status,
-        >;
-        async fn delete_channel(
-            &self,
-            request: tonic::Request<super::DeleteChannelRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/ubc125.v1.ScannerControlService/StartCustomSearch",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "ubc125.v1.ScannerControlService",
+                        "StartCustomSearch",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_close_call_settings(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetCloseCallSettingsRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::DeleteChannelResponse>,
+            tonic::Response<super::GetCloseCallSettingsResponse>,
             tonic::Status,
-        >;
-    }
-    #[derive(Debug)]
-    pub struct ScannerControlServiceServer<T> {
-        inner: Arc<T>,
-        accept_compression_encodings: EnabledCompressionEncodings,
-        send_compression_encodings: EnabledCompressionEncodings,
-        max_decoding_message_size: Option<usize>,
-        max_encoding_message_size: Option<usize>,
-    }
-    impl<T> ScannerControlServiceServer<T> {
-        pub fn new(inner: T) -> Self {
-            Self::from_arc(Arc::new(inner))
-        }
-        pub fn from_arc(inner: Arc<T>) -> Self {
-            Self {
-                inner,
-                accept_compression_encodings: Default::default(),
-                send_compression_encodings: Default::default(),
-                max_decoding_message_size: None,
-                max_encoding_message_size: None,
-            }
-        }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
-        where
-            F: tonic::service::Interceptor,
-        {
-            InterceptedService::new(Self::new(inner), interceptor)
-        }
-        /// Enable decompressing requests with the given encoding.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.accept_compression_encodings.enable(encoding);
-            self
-        }
-        /// Compress responses with the given encoding, if the client supports it.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.send_compression_encodings.enable(encoding);
-            self
-        }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.max_decoding_message_size = Some(limit);
-            self
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/ubc125.v1.ScannerControlService/GetCloseCallSettings",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "ubc125.v1.ScannerControlService",
+                        "GetCloseCallSettings",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.max_encoding_message_size = Some(limit);
-            self
+        pub async fn set_close_call_settings(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetCloseCallSettingsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetCloseCallSettingsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/ubc125.v1.ScannerControlService/SetCloseCallSettings",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "ubc125.v1.ScannerControlService",
+                        "SetCloseCallSettings",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
         }
-    }
-    impl<T, B> tonic::codegen::Service<http::Request<B>>
-    for ScannerControlServiceServer<T>
-    where
-        T: ScannerControlService,
-        B: Body + std::marker::Send + 'static,
-        B::Error: Into<StdError> + std::marker::Send + 'static,
-    {
-        type Response = http::Response<tonic::body::Body>;
-        type Error = std::convert::Infallible;
-        type Future = BoxFuture<Self::Response, Self::Error>;
-        fn poll_ready(
+        pub async fn watch_close_call_hits(
             &mut self,
-            _cx: &mut Context<'_>,
-        ) -> Poll<std::result::Result<(), Self::Error>> {
-            Poll::Ready(Ok(()))
+            request: impl tonic::IntoRequest<super::WatchCloseCallHitsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::WatchCloseCallHitsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/ubc125.v1.ScannerControlService/WatchCloseCallHits",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "ubc125.v1.ScannerControlService",
+                        "WatchCloseCallHits",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
         }
-        fn call(&mut self, req: http::Request<B>) -> Self::Future {
-            match req.uri().path() {
-                "/ubc125.v1.ScannerControlService/GetAudioSettings" => {
+        pub async fn list_service_groups(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListServiceGroupsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListServiceGroupsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/ubc125.v1.ScannerControlService/ListServiceGroups",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "ubc125.v1.ScannerControlService",
+                        "ListServiceGroups",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_service_group_enabled(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetServiceGroupEnabledRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetServiceGroupEnabledResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/ubc125.v1.ScannerControlService/SetServiceGroupEnabled",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "ubc125.v1.ScannerControlService",
+                        "SetServiceGroupEnabled",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn start_service_search(
+            &mut self,
+            request: impl tonic::IntoRequest<super::StartServiceSearchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::StartServiceSearchResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/ubc125.v1.ScannerControlService/StartServiceSearch",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "ubc125.v1.ScannerControlService",
+                        "StartServiceSearch",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn press_keys(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PressKeysRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PressKeysResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/ubc125.v1.ScannerControlService/PressKeys",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("ubc125.v1.ScannerControlService", "PressKeys"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod scanner_control_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ScannerControlServiceServer.
+    #[async_trait]
+    pub trait ScannerControlService: std::marker::Send + std::marker::Sync + 'static {
+        async fn get_audio_settings(
+            &self,
+            request: tonic::Request<super::GetAudioSettingsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetAudioSettingsResponse>,
+            tonic::Status,
+        >;
+        async fn start_scan(
+            &self,
+            request: tonic::Request<super::StartScanRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::StartScanResponse>,
+            tonic::Status,
+        >;
+        async fn hold_scan(
+            &self,
+            request: tonic::Request<super::HoldScanRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::HoldScanResponse>,
+            tonic::Status,
+        >;
+        async fn get_enabled_banks(
+            &self,
+            request: tonic::Request<super::GetEnabledBanksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetEnabledBanksResponse>,
+            tonic::Status,
+        >;
+        async fn set_enabled_banks(
+            &self,
+            request: tonic::Request<super::SetEnabledBanksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetEnabledBanksResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the GetStatus method.
+        type GetStatusStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::GetStatusResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        async fn get_status(
+            &self,
+            request: tonic::Request<super::GetStatusRequest>,
+        ) -> std::result::Result<tonic::Response<Self::GetStatusStream>, tonic::Status>;
+        /// Server streaming response type for the GetDisplay method.
+        type GetDisplayStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::GetDisplayResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        async fn get_display(
+            &self,
+            request: tonic::Request<super::GetDisplayRequest>,
+        ) -> std::result::Result<tonic::Response<Self::GetDisplayStream>, tonic::Status>;
+        async fn get_channel(
+            &self,
+            request: tonic::Request<super::GetChannelRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetChannelResponse>,
+            tonic::Status,
+        >;
+        async fn set_channel(
+            &self,
+            request: tonic::Request<super::SetChannelRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetChannelResponse>,
+            tonic::Status,
+        >;
+        async fn delete_channel(
+            &self,
+            request: tonic::Request<super::DeleteChannelRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteChannelResponse>,
+            tonic::Status,
+        >;
+        async fn list_search_ranges(
+            &self,
+            request: tonic::Request<super::ListSearchRangesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListSearchRangesResponse>,
+            tonic::Status,
+        >;
+        async fn set_search_range(
+            &self,
+            request: tonic::Request<super::SetSearchRangeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetSearchRangeResponse>,
+            tonic::Status,
+        >;
+        async fn set_enabled_search_ranges(
+            &self,
+            request: tonic::Request<super::SetEnabledSearchRangesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetEnabledSearchRangesResponse>,
+            tonic::Status,
+        >;
+        async fn start_custom_search(
+            &self,
+            request: tonic::Request<super::StartCustomSearchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::StartCustomSearchResponse>,
+            tonic::Status,
+        >;
+        async fn get_close_call_settings(
+            &self,
+            request: tonic::Request<super::GetCloseCallSettingsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetCloseCallSettingsResponse>,
+            tonic::Status,
+        >;
+        async fn set_close_call_settings(
+            &self,
+            request: tonic::Request<super::SetCloseCallSettingsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetCloseCallSettingsResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the WatchCloseCallHits method.
+        type WatchCloseCallHitsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::WatchCloseCallHitsResponse,
+                    tonic::Status,
+                >,
+            >
+            + std::marker::Send
+            + 'static;
+        async fn watch_close_call_hits(
+            &self,
+            request: tonic::Request<super::WatchCloseCallHitsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::WatchCloseCallHitsStream>,
+            tonic::Status,
+        >;
+        async fn list_service_groups(
+            &self,
+            request: tonic::Request<super::ListServiceGroupsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListServiceGroupsResponse>,
+            tonic::Status,
+        >;
+        async fn set_service_group_enabled(
+            &self,
+            request: tonic::Request<super::SetServiceGroupEnabledRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetServiceGroupEnabledResponse>,
+            tonic::Status,
+        >;
+        async fn start_service_search(
+            &self,
+            request: tonic::Request<super::StartServiceSearchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::StartServiceSearchResponse>,
+            tonic::Status,
+        >;
+        async fn press_keys(
+            &self,
+            request: tonic::Request<super::PressKeysRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PressKeysResponse>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct ScannerControlServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> ScannerControlServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>>
+    for ScannerControlServiceServer<T>
+    where
+        T: ScannerControlService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/ubc125.v1.ScannerControlService/GetAudioSettings" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetAudioSettingsSvc<T: ScannerControlService>(pub Arc<T>);
+                    impl<
+                        T: ScannerControlService,
+                    > tonic::server::UnaryService<super::GetAudioSettingsRequest>
+                    for GetAudioSettingsSvc<T> {
+                        type Response = super::GetAudioSettingsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetAudioSettingsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ScannerControlService>::get_audio_settings(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetAudioSettingsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ubc125.v1.ScannerControlService/StartScan" => {
+                    #[allow(non_camel_case_types)]
+                    struct StartScanSvc<T: ScannerControlService>(pub Arc<T>);
+                    impl<
+                        T: ScannerControlService,
+                    > tonic::server::UnaryService<super::StartScanRequest>
+                    for StartScanSvc<T> {
+                        type Response = super::StartScanResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::StartScanRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ScannerControlService>::start_scan(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = StartScanSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ubc125.v1.ScannerControlService/HoldScan" => {
+                    #[allow(non_camel_case_types)]
+                    struct HoldScanSvc<T: ScannerControlService>(pub Arc<T>);
+                    impl<
+                        T: ScannerControlService,
+                    > tonic::server::UnaryService<super::HoldScanRequest>
+                    for HoldScanSvc<T> {
+                        type Response = super::HoldScanResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::HoldScanRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ScannerControlService>::hold_scan(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = HoldScanSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ubc125.v1.ScannerControlService/GetEnabledBanks" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetEnabledBanksSvc<T: ScannerControlService>(pub Arc<T>);
+                    impl<
+                        T: ScannerControlService,
+                    > tonic::server::UnaryService<super::GetEnabledBanksRequest>
+                    for GetEnabledBanksSvc<T> {
+                        type Response = super::GetEnabledBanksResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetEnabledBanksRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ScannerControlService>::get_enabled_banks(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetEnabledBanksSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ubc125.v1.ScannerControlService/SetEnabledBanks" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetEnabledBanksSvc<T: ScannerControlService>(pub Arc<T>);
+                    impl<
+                        T: ScannerControlService,
+                    > tonic::server::UnaryService<super::SetEnabledBanksRequest>
+                    for SetEnabledBanksSvc<T> {
+                        type Response = super::SetEnabledBanksResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetEnabledBanksRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ScannerControlService>::set_enabled_banks(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetEnabledBanksSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ubc125.v1.ScannerControlService/GetStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetStatusSvc<T: ScannerControlService>(pub Arc<T>);
+                    impl<
+                        T: ScannerControlService,
+                    > tonic::server::ServerStreamingService<super::GetStatusRequest>
+                    for GetStatusSvc<T> {
+                        type Response = super::GetStatusResponse;
+                        type ResponseStream = T::GetStatusStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ScannerControlService>::get_status(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetStatusSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ubc125.v1.ScannerControlService/GetDisplay" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetDisplaySvc<T: ScannerControlService>(pub Arc<T>);
+                    impl<
+                        T: ScannerControlService,
+                    > tonic::server::ServerStreamingService<super::GetDisplayRequest>
+                    for GetDisplaySvc<T> {
+                        type Response = super::GetDisplayResponse;
+                        type ResponseStream = T::GetDisplayStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetDisplayRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ScannerControlService>::get_display(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetDisplaySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ubc125.v1.ScannerControlService/GetChannel" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetChannelSvc<T: ScannerControlService>(pub Arc<T>);
+                    impl<
+                        T: ScannerControlService,
+                    > tonic::server::UnaryService<super::GetChannelRequest>
+                    for GetChannelSvc<T> {
+                        type Response = super::GetChannelResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetChannelRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ScannerControlService>::get_channel(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetChannelSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ubc125.v1.ScannerControlService/SetChannel" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetChannelSvc<T: ScannerControlService>(pub Arc<T>);
+                    impl<
+                        T: ScannerControlService,
+                    > tonic::server::UnaryService<super::SetChannelRequest>
+                    for SetChannelSvc<T> {
+                        type Response = super::SetChannelResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetChannelRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ScannerControlService>::set_channel(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetChannelSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ubc125.v1.ScannerControlService/DeleteChannel" => {
                     #[allow(non_camel_case_types)]
-                    struct GetAudioSettingsSvc<T: ScannerControlService>(pub Arc<T>);
+                    struct DeleteChannelSvc<T: ScannerControlService>(pub Arc<T>);
                     impl<
                         T: ScannerControlService,
-                    > tonic::server::UnaryService<super::GetAudioSettingsRequest>
-                    for GetAudioSettingsSvc<T> {
-                        type Response = super::GetAudioSettingsResponse;
+                    > tonic::server::UnaryService<super::DeleteChannelRequest>
+                    for DeleteChannelSvc<T> {
+                        type Response = super::DeleteChannelResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetAudioSettingsRequest>,
+                            request: tonic::Request<super::DeleteChannelRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ScannerControlService>::get_audio_settings(
+                                <T as ScannerControlService>::delete_channel(
                                         &inner,
                                         request,
                                     )
@@ -990,7 +2532,7 @@ pub mod scanner_control_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = GetAudioSettingsSvc(inner);
+                        let method = DeleteChannelSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1006,25 +2548,28 @@ pub mod scanner_control_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/ubc125.v1.ScannerControlService/StartScan" => {
+                "/ubc125.v1.ScannerControlService/ListSearchRanges" => {
                     #[allow(non_camel_case_types)]
-                    struct StartScanSvc<T: ScannerControlService>(pub Arc<T>);
+                    struct ListSearchRangesSvc<T: ScannerControlService>(pub Arc<T>);
                     impl<
                         T: ScannerControlService,
-                    > tonic::server::UnaryService<super::StartScanRequest>
-                    for StartScanSvc<T> {
-                        type Response = super::StartScanResponse;
+                    > tonic::server::UnaryService<super::ListSearchRangesRequest>
+                    for ListSearchRangesSvc<T> {
+                        type Response = super::ListSearchRangesResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::StartScanRequest>,
+                            request: tonic::Request<super::ListSearchRangesRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ScannerControlService>::start_scan(&inner, request)
+                                <T as ScannerControlService>::list_search_ranges(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -1036,7 +2581,7 @@ pub mod scanner_control_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = StartScanSvc(inner);
+                        let method = ListSearchRangesSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1052,25 +2597,28 @@ pub mod scanner_control_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/ubc125.v1.ScannerControlService/HoldScan" => {
+                "/ubc125.v1.ScannerControlService/SetSearchRange" => {
                     #[allow(non_camel_case_types)]
-                    struct HoldScanSvc<T: ScannerControlService>(pub Arc<T>);
+                    struct SetSearchRangeSvc<T: ScannerControlService>(pub Arc<T>);
                     impl<
                         T: ScannerControlService,
-                    > tonic::server::UnaryService<super::HoldScanRequest>
-                    for HoldScanSvc<T> {
-                        type Response = super::HoldScanResponse;
+                    > tonic::server::UnaryService<super::SetSearchRangeRequest>
+                    for SetSearchRangeSvc<T> {
+                        type Response = super::SetSearchRangeResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::HoldScanRequest>,
+                            request: tonic::Request<super::SetSearchRangeRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ScannerControlService>::hold_scan(&inner, request)
+                                <T as ScannerControlService>::set_search_range(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -1082,7 +2630,7 @@ pub mod scanner_control_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = HoldScanSvc(inner);
+                        let method = SetSearchRangeSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1098,25 +2646,27 @@ pub mod scanner_control_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/ubc125.v1.ScannerControlService/GetEnabledBanks" => {
+                "/ubc125.v1.ScannerControlService/SetEnabledSearchRanges" => {
                     #[allow(non_camel_case_types)]
-                    struct GetEnabledBanksSvc<T: ScannerControlService>(pub Arc<T>);
+                    struct SetEnabledSearchRangesSvc<T: ScannerControlService>(
+                        pub Arc<T>,
+                    );
                     impl<
                         T: ScannerControlService,
-                    > tonic::server::UnaryService<super::GetEnabledBanksRequest>
-                    for GetEnabledBanksSvc<T> {
-                        type Response = super::GetEnabledBanksResponse;
+                    > tonic::server::UnaryService<super::SetEnabledSearchRangesRequest>
+                    for SetEnabledSearchRangesSvc<T> {
+                        type Response = super::SetEnabledSearchRangesResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetEnabledBanksRequest>,
+                            request: tonic::Request<super::SetEnabledSearchRangesRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ScannerControlService>::get_enabled_banks(
+                                <T as ScannerControlService>::set_enabled_search_ranges(
                                         &inner,
                                         request,
                                     )
@@ -1131,7 +2681,7 @@ pub mod scanner_control_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = GetEnabledBanksSvc(inner);
+                        let method = SetEnabledSearchRangesSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1147,25 +2697,25 @@ pub mod scanner_control_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/ubc125.v1.ScannerControlService/SetEnabledBanks" => {
+                "/ubc125.v1.ScannerControlService/StartCustomSearch" => {
                     #[allow(non_camel_case_types)]
-                    struct SetEnabledBanksSvc<T: ScannerControlService>(pub Arc<T>);
+                    struct StartCustomSearchSvc<T: ScannerControlService>(pub Arc<T>);
                     impl<
                         T: ScannerControlService,
-                    > tonic::server::UnaryService<super::SetEnabledBanksRequest>
-                    for SetEnabledBanksSvc<T> {
-                        type Response = super::SetEnabledBanksResponse;
+                    > tonic::server::UnaryService<super::StartCustomSearchRequest>
+                    for StartCustomSearchSvc<T> {
+                        type Response = super::StartCustomSearchResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::SetEnabledBanksRequest>,
+                            request: tonic::Request<super::StartCustomSearchRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ScannerControlService>::set_enabled_banks(
+                                <T as ScannerControlService>::start_custom_search(
                                         &inner,
                                         request,
                                     )
@@ -1180,7 +2730,7 @@ pub mod scanner_control_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = SetEnabledBanksSvc(inner);
+                        let method = StartCustomSearchSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1196,26 +2746,128 @@ pub mod scanner_control_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/ubc125.v1.ScannerControlService/GetStatus" => {
+                "/ubc125.v1.ScannerControlService/GetCloseCallSettings" => {
                     #[allow(non_camel_case_types)]
-                    struct GetStatusSvc<T: ScannerControlService>(pub Arc<T>);
+                    struct GetCloseCallSettingsSvc<T: ScannerControlService>(pub Arc<T>);
                     impl<
                         T: ScannerControlService,
-                    > tonic::server::ServerStreamingService<super::GetStatusRequest>
-                    for GetStatusSvc<T> {
-                        type Response = super::GetStatusResponse;
-                        type ResponseStream = T::GetStatusStream;
+                    > tonic::server::UnaryService<super::GetCloseCallSettingsRequest>
+                    for GetCloseCallSettingsSvc<T> {
+                        type Response = super::GetCloseCallSettingsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetCloseCallSettingsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ScannerControlService>::get_close_call_settings(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetCloseCallSettingsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ubc125.v1.ScannerControlService/SetCloseCallSettings" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetCloseCallSettingsSvc<T: ScannerControlService>(pub Arc<T>);
+                    impl<
+                        T: ScannerControlService,
+                    > tonic::server::UnaryService<super::SetCloseCallSettingsRequest>
+                    for SetCloseCallSettingsSvc<T> {
+                        type Response = super::SetCloseCallSettingsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetCloseCallSettingsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ScannerControlService>::set_close_call_settings(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetCloseCallSettingsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ubc125.v1.ScannerControlService/WatchCloseCallHits" => {
+                    #[allow(non_camel_case_types)]
+                    struct WatchCloseCallHitsSvc<T: ScannerControlService>(pub Arc<T>);
+                    impl<
+                        T: ScannerControlService,
+                    > tonic::server::ServerStreamingService<
+                        super::WatchCloseCallHitsRequest,
+                    > for WatchCloseCallHitsSvc<T> {
+                        type Response = super::WatchCloseCallHitsResponse;
+                        type ResponseStream = T::WatchCloseCallHitsStream;
                         type Future = BoxFuture<
                             tonic::Response<Self::ResponseStream>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetStatusRequest>,
+                            request: tonic::Request<super::WatchCloseCallHitsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ScannerControlService>::get_status(&inner, request)
+                                <T as ScannerControlService>::watch_close_call_hits(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -1227,7 +2879,7 @@ pub mod scanner_control_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = GetStatusSvc(inner);
+                        let method = WatchCloseCallHitsSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1243,25 +2895,28 @@ pub mod scanner_control_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/ubc125.v1.ScannerControlService/GetChannel" => {
+                "/ubc125.v1.ScannerControlService/ListServiceGroups" => {
                     #[allow(non_camel_case_types)]
-                    struct GetChannelSvc<T: ScannerControlService>(pub Arc<T>);
+                    struct ListServiceGroupsSvc<T: ScannerControlService>(pub Arc<T>);
                     impl<
                         T: ScannerControlService,
-                    > tonic::server::UnaryService<super::GetChannelRequest>
-                    for GetChannelSvc<T> {
-                        type Response = super::GetChannelResponse;
+                    > tonic::server::UnaryService<super::ListServiceGroupsRequest>
+                    for ListServiceGroupsSvc<T> {
+                        type Response = super::ListServiceGroupsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetChannelRequest>,
+                            request: tonic::Request<super::ListServiceGroupsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ScannerControlService>::get_channel(&inner, request)
+                                <T as ScannerControlService>::list_service_groups(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -1273,7 +2928,7 @@ pub mod scanner_control_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = GetChannelSvc(inner);
+                        let method = ListServiceGroupsSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1289,25 +2944,30 @@ pub mod scanner_control_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/ubc125.v1.ScannerControlService/SetChannel" => {
+                "/ubc125.v1.ScannerControlService/SetServiceGroupEnabled" => {
                     #[allow(non_camel_case_types)]
-                    struct SetChannelSvc<T: ScannerControlService>(pub Arc<T>);
+                    struct SetServiceGroupEnabledSvc<T: ScannerControlService>(
+                        pub Arc<T>,
+                    );
                     impl<
                         T: ScannerControlService,
-                    > tonic::server::UnaryService<super::SetChannelRequest>
-                    for SetChannelSvc<T> {
-                        type Response = super::SetChannelResponse;
+                    > tonic::server::UnaryService<super::SetServiceGroupEnabledRequest>
+                    for SetServiceGroupEnabledSvc<T> {
+                        type Response = super::SetServiceGroupEnabledResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::SetChannelRequest>,
+                            request: tonic::Request<super::SetServiceGroupEnabledRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ScannerControlService>::set_channel(&inner, request)
+                                <T as ScannerControlService>::set_service_group_enabled(
+                                        &inner,
+                                        request,
+                                    )
                                     .await
                             };
                             Box::pin(fut)
@@ -1319,7 +2979,7 @@ pub mod scanner_control_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = SetChannelSvc(inner);
+                        let method = SetServiceGroupEnabledSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1335,25 +2995,25 @@ pub mod scanner_control_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/ubc125.v1.ScannerControlService/DeleteChannel" => {
+                "/ubc125.v1.ScannerControlService/StartServiceSearch" => {
                     #[allow(non_camel_case_types)]
-                    struct DeleteChannelSvc<T: ScannerControlService>(pub Arc<T>);
+                    struct StartServiceSearchSvc<T: ScannerControlService>(pub Arc<T>);
                     impl<
                         T: ScannerControlService,
-                    > tonic::server::UnaryService<super::DeleteChannelRequest>
-                    for DeleteChannelSvc<T> {
-                        type Response = super::DeleteChannelResponse;
+                    > tonic::server::UnaryService<super::StartServiceSearchRequest>
+                    for StartServiceSearchSvc<T> {
+                        type Response = super::StartServiceSearchResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DeleteChannelRequest>,
+                            request: tonic::Request<super::StartServiceSearchRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ScannerControlService>::delete_channel(
+                                <T as ScannerControlService>::start_service_search(
                                         &inner,
                                         request,
                                     )
@@ -1368,7 +3028,53 @@ pub mod scanner_control_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = DeleteChannelSvc(inner);
+                        let method = StartServiceSearchSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/ubc125.v1.ScannerControlService/PressKeys" => {
+                    #[allow(non_camel_case_types)]
+                    struct PressKeysSvc<T: ScannerControlService>(pub Arc<T>);
+                    impl<
+                        T: ScannerControlService,
+                    > tonic::server::UnaryService<super::PressKeysRequest>
+                    for PressKeysSvc<T> {
+                        type Response = super::PressKeysResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PressKeysRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ScannerControlService>::press_keys(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PressKeysSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -0,0 +1,46 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::PathBuf;
+use clap::Args;
+use crate::scanner::ScannerClient;
+
+#[derive(Args)]
+pub struct BackupArgs {
+    /// Serial device path, tcp://host:port for a serial-over-TCP bridge,
+    /// replay:FILE to play back a capture, or auto to find a USB-connected
+    /// scanner
+    #[arg(short, long, default_value_t = String::from("auto"))]
+    pub device: String,
+    /// File to write the backup to, as JSON
+    #[arg(short, long)]
+    pub output: PathBuf,
+}
+
+// ubc125 backup -o radio.json
+
+pub fn run(args: &BackupArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let mut client = ScannerClient::open(&args.device)?;
+    let backup = client.backup(|what, done, total| {
+        if total > 1 {
+            eprint!("\rReading {} {}/{}", what, done, total);
+            if done == total {
+                eprintln!();
+            }
+        } else {
+            eprintln!("Read {}", what);
+        }
+    })?;
+
+    // Only touch the file once everything has been read
+    let mut file = BufWriter::new(File::create(&args.output)?);
+    serde_json::to_writer_pretty(&mut file, &backup)?;
+    writeln!(file)?;
+    file.flush()?;
+    println!(
+        "Backed up {} channels from the {} to {}",
+        backup.channels.len(),
+        backup.model,
+        args.output.display()
+    );
+    Ok(())
+}
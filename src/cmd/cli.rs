@@ -19,4 +19,7 @@ pub struct Cli {
 pub enum Commands {
     Serve(super::serve::ServeArgs),
     Console(super::console::ConsoleArgs),
+    Simulate(super::simulate::SimulateArgs),
+    Backup(super::backup::BackupArgs),
+    Settings(super::settings::SettingsArgs),
 }
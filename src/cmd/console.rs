@@ -1,7 +1,10 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::io;
+use std::path::PathBuf;
+use std::sync::mpsc;
 use std::time::{Duration, Instant};
 
+use chrono::{DateTime, Local};
 use clap::Args;
 use crossterm::{
     event::{self, Event, KeyCode},
@@ -13,23 +16,70 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Modifier, Style, Color},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Paragraph, Row, Table, TableState, Tabs},
+    widgets::{Block, Borders, Clear, Paragraph, Row, Table, TableState, Tabs, Wrap},
     Terminal,
 };
-use crate::scanner::ScannerClient;
+use crate::scanner::{Frequency, ScannerClient, ScannerError};
+use crate::scanner::channel::{ChannelInfo, ChannelName, Modulation};
+use crate::scanner::close_call::{CloseCallDetector, CloseCallHit, CloseCallMode, CloseCallSettings};
+use crate::scanner::display::{CharStyle, DisplayStatus, LINE_WIDTH};
+use crate::scanner::keys::{self, Key, KeyAction};
+use crate::scanner::model::{self, Capabilities};
+use crate::scanner::search::{SEARCH_RANGE_COUNT, SearchRange, ServiceGroup, ServiceGroups};
+use crate::scanner::status::ScanStatus;
+use crate::scanner::tone::Tone;
+use crate::scanner::actor::{ConnectionState, ScannerHandle};
 
 #[derive(Args)]
 pub struct ConsoleArgs {
-    #[arg(short, long, default_value_t = String::from("/dev/ttyACM0"))]
+    /// Serial device path, tcp://host:port for a serial-over-TCP bridge,
+    /// replay:FILE to play back a capture, or auto to find a USB-connected
+    /// scanner
+    #[arg(short, long, default_value_t = String::from("auto"))]
     pub console_device: String,
+    /// Record every exchange with the scanner to this JSONL file
+    #[arg(short, long)]
+    pub record: Option<PathBuf>,
 }
 
-#[derive(Clone, Debug)]
-struct Channel {
-    index: u32,
-    name: String,
-    frequency: String,
-    modulation: String,
+/// How many channels are read per program mode session while loading a bank.
+const FETCH_CHUNK: usize = 10;
+
+/// How many times a channel read is tried before the bank shows it blank.
+const FETCH_ATTEMPTS: usize = 3;
+
+/// How many Close Call hits the Monitor tab remembers.
+const CLOSE_CALL_HISTORY: usize = 50;
+
+/// The custom search ranges and which are enabled.
+type SearchRanges = (Vec<SearchRange>, [bool; SEARCH_RANGE_COUNT]);
+
+/// Results of scanner requests, delivered back to the UI loop.
+enum AppEvent {
+    Channels(Vec<u32>, Result<Vec<ChannelInfo>, ScannerError>),
+    Status(Result<String, ScannerError>, Result<DisplayStatus, ScannerError>),
+    Squelch(u8, Result<(), ScannerError>),
+    Lockouts(Result<Vec<Frequency>, ScannerError>),
+    Search(Result<SearchRanges, ScannerError>),
+    Services(Result<ServiceGroups, ScannerError>),
+    Written(Change, Result<(), ScannerError>),
+}
+
+/// A write to the radio, and what it changes in the console's copy of the
+/// radio's state once it has been accepted.
+enum Change {
+    Channel(ChannelInfo),
+    ChannelDeleted(u32),
+    ScanBanks(Vec<bool>),
+    CloseCall(CloseCallSettings),
+    // The lockout list is read again when next shown
+    LockedOut,
+    Unlocked(Frequency),
+    SearchRange(SearchRange),
+    SearchGroups([bool; SEARCH_RANGE_COUNT]),
+    ServiceGroups(ServiceGroups),
+    // Key presses and searches, which change nothing held locally
+    Pressed,
 }
 
 #[derive(Default, PartialEq)]
@@ -37,8 +87,24 @@ enum InputMode {
     #[default]
     Normal,
     Editing(EditState),
+    EditingRange(RangeEditState),
     ConfirmDelete,
     SetSquelch,
+    Tune {
+        frequency: String,
+        // Why the last attempt to tune was refused
+        error: Option<String>,
+    },
+}
+
+#[derive(Clone, PartialEq)]
+struct RangeEditState {
+    range: SearchRange,
+    lower: String,
+    upper: String,
+    upper_active: bool,
+    // Why the last attempt to save was refused
+    error: Option<String>,
 }
 
 #[derive(Clone, Default, PartialEq)]
@@ -46,98 +112,140 @@ enum EditField {
     #[default]
     Frequency,
     Name,
+    Modulation,
+    Tone,
 }
 
-#[derive(Clone, Default, PartialEq)]
+#[derive(Clone, PartialEq)]
 struct EditState {
+    // The channel being edited; fields without an input are written back
+    // unchanged
+    channel: ChannelInfo,
     frequency: String,
     name: String,
+    // Typed by name ("CTCSS 103.5", "DCS 023") or picked with Left/Right
+    tone: String,
     active_field: EditField,
-}
-
-struct ScanStatus {
-    frequency: String,
-    bank: String,
-    channel_name: String,
-    raw: String,
-    signal_detected: bool,
-}
-
-impl Default for ScanStatus {
-    fn default() -> Self {
-        Self {
-            frequency: "---".to_string(),
-            bank: "-".to_string(),
-            channel_name: "".to_string(),
-            raw: "".to_string(),
-            signal_detected: false,
-        }
-    }
+    // Why the last attempt to save was refused
+    error: Option<String>,
 }
 
 struct App {
     model: String,
+    // Frequencies typed into dialogs are checked against these
+    capabilities: &'static Capabilities,
     version: String,
     volume: String,
     squelch: String,
     squelch_input: String,
-    scan_status: ScanStatus,
+    // None while the scanner is between channels
+    scan_status: Option<ScanStatus>,
+    // Whether scan_status is from the latest poll, rather than kept from
+    // before a poll failed
+    scan_status_current: bool,
+    // The last GLG reply, or the error polling it
+    scan_raw: String,
+    // What the radio's LCD shows, None until read
+    display: Option<DisplayStatus>,
+    // None if the settings couldn't be read
+    close_call: Option<CloseCallSettings>,
+    close_call_detector: CloseCallDetector,
+    // Newest first
+    close_call_hits: VecDeque<(DateTime<Local>, CloseCallHit)>,
     // Tab state
     tabs: Vec<String>,
     selected_tab: usize,
-    // Channel data (Index 1-500)
-    channels: Vec<Option<Channel>>,
+    // Channel data, indexed from 1 up to the channel count
+    channels: Vec<Option<ChannelInfo>>,
     fetch_queue: VecDeque<u32>,
-    in_prg_mode: bool,
-    banks: Vec<bool>, // 10 banks (0-9 corresponds to Bank 1-10)
+    fetch_in_flight: bool,
+    // Failed reads per channel, and why the last one failed
+    fetch_failures: HashMap<u32, usize>,
+    fetch_error: Option<String>,
+    poll_in_flight: bool,
+    // Set once a program mode session has left the radio holding
+    scan_paused: bool,
+    banks: Vec<bool>, // 0-9 corresponds to Bank 1-10
+    // Global lockout list, None until read; Err holds why the read failed
+    lockouts: Option<Result<Vec<Frequency>, String>>,
+    lockouts_in_flight: bool,
+    // Custom search ranges, None until read; Err holds why the read failed
+    search: Option<Result<SearchRanges, String>>,
+    search_in_flight: bool,
+    // Service search groups, None until read; Err holds why the read failed
+    services: Option<Result<ServiceGroups, String>>,
+    services_in_flight: bool,
+    // Why the last write to the radio failed, until one succeeds
+    write_error: Option<String>,
     input_mode: InputMode,
     table_state: TableState,
 }
 
 impl App {
-    fn new(client: &mut ScannerClient) -> Self {
+    async fn new(scanner: &ScannerHandle) -> Self {
+        let (model, version, capabilities) = match scanner.call(|c| c.model().cloned()).await {
+            Ok(model) if model.is_known() => (model.name, model.firmware, model.capabilities),
+            Ok(model) => {
+                (format!("{} (unknown model)", model.name), model.firmware, model.capabilities)
+            }
+            Err(e) => (format!("Err: {}", e), String::new(), &model::UNKNOWN),
+        };
+
         let mut tabs = vec!["Monitor".to_string()];
-        for i in 1..=10 {
+        for i in 1..=capabilities.bank_count {
             tabs.push(format!("Bank {}", i));
         }
-
-        let model = client.send_command("MDL").unwrap_or_else(|e| format!("Err: {}", e));
-        let version = client.send_command("VER").unwrap_or_else(|e| format!("Err: {}", e));
-        let volume = client.get_volume().unwrap_or_else(|e| format!("Err: {}", e));
-        let squelch = client.get_squelch().unwrap_or_else(|e| format!("Err: {}", e));
+        tabs.push("Lockouts".to_string());
+        tabs.push("Search".to_string());
+        tabs.push("Services".to_string());
+        let volume = scanner
+            .call(|c| c.get_volume())
+            .await
+            .map_or_else(|e| format!("Err: {}", e), |v| v.to_string());
+        let squelch = scanner
+            .call(|c| c.get_squelch())
+            .await
+            .map_or_else(|e| format!("Err: {}", e), |v| v.to_string());
 
         // Fetch initial bank status
-        // Enter PRG mode temporarily
-        let _ = client.send_command("PRG");
-        let scg_resp = client.send_command("SCG").unwrap_or_default();
-        let _ = client.send_command("EPG");
-        let _ = client.send_command("KEY,S,P");
-
-        // Parse SCG: SCG,0101010101 (0=On, 1=Off)
-        let mut banks = vec![true; 10]; // Default all on if parse fails
-        let parts: Vec<&str> = scg_resp.split(',').collect();
-        if parts.len() >= 2 && parts[0] == "SCG" {
-            let mask = parts[1].trim();
-            if mask.len() >= 10 {
-                for (i, c) in mask.chars().take(10).enumerate() {
-                    banks[i] = c == '0';
-                }
-            }
-        }
+        let banks = scanner
+            .call(|c| c.program()?.resume_scan().get_scan_banks())
+            .await
+            .unwrap_or_else(|_| vec![true; capabilities.bank_count]); // Default all on if the read fails
+        let close_call = scanner.call(|c| c.get_close_call()).await.ok();
 
         Self {
             model,
+            capabilities,
             version,
             volume,
             squelch,
             squelch_input: String::new(),
-            scan_status: ScanStatus::default(),
+            scan_status: None,
+            scan_status_current: false,
+            scan_raw: String::new(),
+            display: None,
+            close_call,
+            close_call_detector: CloseCallDetector::default(),
+            close_call_hits: VecDeque::new(),
             tabs,
             selected_tab: 0,
-            channels: vec![None; 501], // 1-based indexing, 500 channels
+            // 1-based indexing
+            channels: vec![None; usize::from(capabilities.channel_count) + 1],
             fetch_queue: VecDeque::new(),
-            in_prg_mode: false,
+            fetch_in_flight: false,
+            fetch_failures: HashMap::new(),
+            fetch_error: None,
+            poll_in_flight: false,
+            scan_paused: false,
             banks,
+            lockouts: None,
+            lockouts_in_flight: false,
+            search: None,
+            search_in_flight: false,
+            services: None,
+            services_in_flight: false,
+            write_error: None,
             input_mode: InputMode::Normal,
             table_state: TableState::default().with_selected(Some(0)),
         }
@@ -145,6 +253,8 @@ impl App {
 
     fn next_tab(&mut self) {
         self.selected_tab = (self.selected_tab + 1) % self.tabs.len();
+        // Polling stops away from the Monitor tab
+        self.scan_status_current = false;
         self.queue_channels_for_tab();
     }
 
@@ -154,13 +264,57 @@ impl App {
         } else {
             self.selected_tab = self.tabs.len() - 1;
         }
+        self.scan_status_current = false;
         self.queue_channels_for_tab();
     }
 
+    /// The tab after the banks, listing the global lockouts.
+    fn lockouts_tab(&self) -> usize {
+        self.capabilities.bank_count + 1
+    }
+
+    /// The custom search ranges.
+    fn search_tab(&self) -> usize {
+        self.capabilities.bank_count + 2
+    }
+
+    /// The service search groups.
+    fn services_tab(&self) -> usize {
+        self.capabilities.bank_count + 3
+    }
+
+    /// The bank shown on the selected tab, if it is a bank tab.
+    fn selected_bank(&self) -> Option<u32> {
+        (1..=self.capabilities.bank_count)
+            .contains(&self.selected_tab)
+            .then_some(self.selected_tab as u32)
+    }
+
+    fn lockout_list(&self) -> &[Frequency] {
+        match &self.lockouts {
+            Some(Ok(lockouts)) => lockouts,
+            _ => &[],
+        }
+    }
+
+    /// Rows in the table on the selected tab.
+    fn row_count(&self) -> usize {
+        if self.selected_tab == self.lockouts_tab() {
+            self.lockout_list().len()
+        } else if self.selected_tab == self.search_tab() {
+            SEARCH_RANGE_COUNT
+        } else if self.selected_tab == self.services_tab() {
+            ServiceGroup::ALL.len()
+        } else {
+            self.capabilities.channels_per_bank() as usize
+        }
+    }
+
     fn next_channel(&mut self) {
+        let rows = self.row_count().max(1);
         let i = match self.table_state.selected() {
             Some(i) => {
-                if i >= 49 {
+                if i + 1 >= rows {
                     0
                 } else {
                     i + 1
@@ -172,10 +326,11 @@ impl App {
     }
 
     fn previous_channel(&mut self) {
+        let rows = self.row_count().max(1);
         let i = match self.table_state.selected() {
             Some(i) => {
-                if i == 0 {
-                    49
+                if i == 0 || i >= rows {
+                    rows - 1
                 } else {
                     i - 1
                 }
@@ -186,23 +341,47 @@ impl App {
     }
 
     fn selected_channel_index(&self) -> u32 {
-        if self.selected_tab == 0 {
+        let Some(bank) = self.selected_bank() else {
             return 0;
-        }
-        let bank = self.selected_tab as u32;
+        };
         let row = self.table_state.selected().unwrap_or(0) as u32;
-        (bank - 1) * 50 + row + 1
+        self.capabilities.bank_channels(bank).start() + row
+    }
+
+    /// The selected search range and whether it is enabled.
+    fn selected_search_range(&self) -> Option<(SearchRange, bool)> {
+        let Some(Ok((ranges, enabled))) = &self.search else {
+            return None;
+        };
+        let row = self.table_state.selected().unwrap_or(0);
+        Some((*ranges.get(row)?, *enabled.get(row)?))
+    }
+
+    fn selected_lockout(&self) -> Option<Frequency> {
+        let row = self.table_state.selected().unwrap_or(0);
+        self.lockout_list().get(row).copied()
     }
 
     fn queue_channels_for_tab(&mut self) {
-        if self.selected_tab == 0 {
+        if self.selected_tab == self.lockouts_tab() {
+            // Read the list afresh each time the tab is opened
+            self.lockouts = None;
             return;
         }
-        let bank = self.selected_tab as u32; // Tab 1 = Bank 1
-        let start_idx = (bank - 1) * 50 + 1;
-        let end_idx = bank * 50;
-
-        for i in start_idx..=end_idx {
+        if self.selected_tab == self.search_tab() {
+            self.search = None;
+            return;
+        }
+        if self.selected_tab == self.services_tab() {
+            self.services = None;
+            return;
+        }
+        let Some(bank) = self.selected_bank() else {
+            return;
+        };
+        // Opening the tab again gives channels that failed another go
+        self.fetch_failures.clear();
+        for i in self.capabilities.bank_channels(bank) {
             if self.channels[i as usize].is_none() {
                 // Avoid adding duplicates if possible, or just push
                 if !self.fetch_queue.contains(&i) {
@@ -212,92 +391,132 @@ impl App {
         }
     }
 
-    fn update_channel(&mut self, response: String) -> bool {
-        // Expected format: CIN,[INDEX],[NAME],[FRQ],[MOD],...
-        let parts: Vec<&str> = response.split(',').collect();
-        if parts.len() >= 5 && parts[0] == "CIN" {
-            if let Ok(idx) = parts[1].parse::<usize>() {
-                if idx > 0 && idx <= 500 {
-                    let mut freq = parts[3].to_string();
-                    if freq.len() == 8 && freq.chars().all(|c| c.is_digit(10)) {
-                        if freq == "00000000" {
-                            freq = "".to_string();
-                        } else {
-                            let mhz = freq[0..4].trim_start_matches('0');
-                            let mhz = if mhz.is_empty() { "0" } else { mhz };
-                            let khz = freq[4..8].trim_end_matches('0');
-                            if khz.is_empty() {
-                                freq = format!("{}.0", mhz);
-                            } else {
-                                freq = format!("{}.{}", mhz, khz);
-                            }
-                        }
-                    }
-
-                    self.channels[idx] = Some(Channel {
-                        index: idx as u32,
-                        name: parts[2].to_string(),
-                        frequency: freq,
-                        modulation: parts[4].to_string(),
-                    });
-                    return true;
+    /// Queues channels whose read failed for another try, unless the error
+    /// won't go away by itself or they have had [`FETCH_ATTEMPTS`].
+    fn fetch_failed(&mut self, indices: Vec<u32>, e: ScannerError) {
+        if e.is_transient() && self.selected_bank().is_some() {
+            for index in indices {
+                let failures = self.fetch_failures.entry(index).or_default();
+                *failures += 1;
+                if *failures < FETCH_ATTEMPTS {
+                    self.fetch_queue.push_back(index);
                 }
             }
         }
-        false
+        self.fetch_error = Some(e.to_string());
+    }
+
+    fn update_channel(&mut self, channel: ChannelInfo) {
+        let idx = channel.index as usize;
+        self.channels[idx] = Some(channel);
     }
 
     fn update_scan_status(&mut self, response: String) {
-        self.scan_status.raw = response.clone();
-        let parts: Vec<&str> = response.split(',').collect();
-        if parts.len() >= 8 && parts[0] == "GLG" {
-            // GLG,[Freq],[Modulation],,[Bank?],,,[Channel Name],
-            // Example: GLG,01285500,AM,,0,,,GLOS APPR,
-            
-            // Format frequency: 01285500 -> 128.5500
-            let raw_freq = parts[1];
-            if raw_freq.len() >= 8 {
-                let mhz = &raw_freq[0..4].trim_start_matches('0');
-                let khz = &raw_freq[4..8];
-                let mhz = if mhz.is_empty() { "0" } else { mhz };
-                self.scan_status.frequency = format!("{}.{}", mhz, khz);
-            } else {
-                self.scan_status.frequency = raw_freq.to_string();
+        // Keep showing the last status if the reply can't be parsed
+        match ScanStatus::parse(&response) {
+            Ok(status) => {
+                self.scan_status = status;
+                self.scan_status_current = true;
             }
+            Err(_) => self.scan_status_current = false,
+        }
+        self.scan_raw = response;
+    }
 
-            self.scan_status.channel_name = parts[7].trim().to_string();
-
-            // Signal Status appears to be at index 8 (1 = Squelch Open/Detected)
-            // Based on example: GLG,01239750,AM,,0,,,BHX RADAR,1,0,,52,
-            if parts.len() > 8 {
-                 self.scan_status.signal_detected = parts[8].trim() == "1";
-            }
-            
-            // Calculate bank from Channel Index (index 11)
-            // Example: GLG,01239750,AM,,0,,,BHX RADAR,1,0,,52,
-            // Channel 52 is Bank 2. ((52-1)/50)+1 = 2.
-            if parts.len() > 11 {
-                 if let Ok(index) = parts[11].trim().parse::<u32>() {
-                     if index > 0 {
-                         let bank = ((index - 1) / 50) + 1;
-                         self.scan_status.bank = bank.to_string();
-                     }
-                 }
+    /// The frequency being received right now, for 'o' to lock out.
+    fn receiving(&self) -> Result<Frequency, String> {
+        match &self.scan_status {
+            _ if !self.scan_status_current => {
+                Err("Can't lock out, the scanner's status is out of date".to_string())
             }
+            Some(status) if status.squelch_open => Ok(status.frequency),
+            _ => Err("Nothing is being received to lock out".to_string()),
         }
     }
 
-    fn get_scg_string(&self) -> String {
-        let mut s = String::from("SCG,");
-        for &b in &self.banks {
-            s.push(if b { '0' } else { '1' });
+    /// Records a Close Call hit if one started with this status.
+    fn update_close_call(&mut self, display: &DisplayStatus) {
+        if let Some(hit) = self.close_call_detector.update(self.scan_status.as_ref(), display) {
+            self.close_call_hits.push_front((Local::now(), hit));
+            self.close_call_hits.truncate(CLOSE_CALL_HISTORY);
+        }
+    }
+
+    /// Applies a write to the local state if the radio took it, otherwise
+    /// keeps the error for the status bar.
+    fn apply(&mut self, change: Change, result: Result<(), ScannerError>) {
+        if let Err(e) = result {
+            self.write_error = Some(e.to_string());
+            return;
+        }
+        self.write_error = None;
+        match change {
+            Change::Channel(channel) => self.update_channel(channel),
+            Change::ChannelDeleted(idx) => {
+                self.channels[idx as usize] = None;
+                self.fetch_queue.push_back(idx);
+            }
+            Change::ScanBanks(banks) => self.banks = banks,
+            Change::CloseCall(settings) => self.close_call = Some(settings),
+            Change::LockedOut => self.lockouts = None,
+            Change::Unlocked(frequency) => {
+                if let Some(Ok(lockouts)) = &mut self.lockouts {
+                    lockouts.retain(|&f| f != frequency);
+                }
+            }
+            Change::SearchRange(range) => {
+                if let Some(Ok((ranges, _))) = &mut self.search
+                    && let Some(slot) = ranges.get_mut(range.index as usize - 1)
+                {
+                    *slot = range;
+                }
+            }
+            Change::SearchGroups(groups) => {
+                if let Some(Ok((_, enabled))) = &mut self.search {
+                    *enabled = groups;
+                }
+            }
+            Change::ServiceGroups(groups) => {
+                if let Some(Ok(services)) = &mut self.services {
+                    *services = groups;
+                }
+            }
+            Change::Pressed => {}
         }
-        s
     }
 }
 
 
 
+/// Styles each character of the LCD the way the radio draws it.
+fn lcd_lines(display: &DisplayStatus) -> Vec<Line<'static>> {
+    display
+        .lines
+        .iter()
+        .map(|line| {
+            let base = if line.large {
+                Style::default().add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            let spans: Vec<Span> = line
+                .text
+                .chars()
+                .zip(line.styles.iter())
+                .map(|(c, style)| {
+                    let style = match style {
+                        CharStyle::Normal => base,
+                        CharStyle::Reverse => base.add_modifier(Modifier::REVERSED),
+                        CharStyle::Underline => base.add_modifier(Modifier::UNDERLINED),
+                    };
+                    Span::styled(c.to_string(), style)
+                })
+                .collect();
+            Line::from(spans)
+        })
+        .collect()
+}
+
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
         .direction(Direction::Vertical)
@@ -324,11 +543,35 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
-pub fn run(args: &ConsoleArgs) -> Result<(), Box<dyn std::error::Error>> {
-    // Setup serial port via ScannerClient
-    let mut client = ScannerClient::new(&args.console_device)?;
+/// Queues `f` on the scanner actor and routes its result back to the UI loop.
+fn dispatch(
+    scanner: &ScannerHandle,
+    events: &mpsc::Sender<AppEvent>,
+    f: impl FnOnce(&mut ScannerClient) -> AppEvent + Send + 'static,
+) {
+    let events = events.clone();
+    scanner.execute(move |client| {
+        let _ = events.send(f(client));
+    });
+}
 
-    let mut app = App::new(&mut client);
+pub async fn run(args: &ConsoleArgs) -> Result<(), Box<dyn std::error::Error>> {
+    // Setup transport via ScannerClient, owned by the scanner actor
+    let mut client = ScannerClient::open(&args.console_device)?;
+    if let Some(path) = &args.record {
+        client.record_to(path)?;
+    }
+    let scanner = ScannerHandle::spawn(client);
+
+    let app = App::new(&scanner).await;
+
+    // The UI loop blocks on terminal input, so keep it off the runtime workers
+    tokio::task::spawn_blocking(move || run_ui(app, scanner)).await??;
+    Ok(())
+}
+
+fn run_ui(mut app: App, scanner: ScannerHandle) -> io::Result<()> {
+    let (events_tx, events_rx) = mpsc::channel();
 
     // Setup terminal
     enable_raw_mode()?;
@@ -338,35 +581,117 @@ pub fn run(args: &ConsoleArgs) -> Result<(), Box<dyn std::error::Error>> {
     let mut terminal = Terminal::new(backend)?;
 
     let mut last_poll = Instant::now();
+    let connection = scanner.connection();
 
     // Main loop
     loop {
-        // Mode Management
-        if app.selected_tab > 0 && !app.in_prg_mode {
-            let _ = client.send_command("PRG");
-            app.in_prg_mode = true;
-        } else if app.selected_tab == 0 && app.in_prg_mode {
-            let _ = client.send_command("EPG");
-            // Automatically resume scanning when returning to Monitor
-            let _ = client.send_command("KEY,S,P");
-            app.in_prg_mode = false;
+        // Apply results from the scanner actor
+        while let Ok(event) = events_rx.try_recv() {
+            match event {
+                AppEvent::Channels(indices, resp) => {
+                    app.fetch_in_flight = false;
+                    match resp {
+                        Ok(channels) => {
+                            app.fetch_error = None;
+                            channels.into_iter().for_each(|c| app.update_channel(c));
+                        }
+                        Err(e) => app.fetch_failed(indices, e),
+                    }
+                }
+                AppEvent::Status(resp, display) => {
+                    app.poll_in_flight = false;
+                    app.update_scan_status(resp.unwrap_or_else(|e| format!("Err: {}", e)));
+                    if let Ok(display) = display {
+                        app.update_close_call(&display);
+                        app.display = Some(display);
+                    }
+                }
+                AppEvent::Squelch(lvl, resp) => {
+                    if resp.is_ok() {
+                        app.squelch = lvl.to_string();
+                    }
+                }
+                AppEvent::Search(resp) => {
+                    app.search_in_flight = false;
+                    app.search = Some(resp.map_err(|e| e.to_string()));
+                }
+                AppEvent::Services(resp) => {
+                    app.services_in_flight = false;
+                    app.services = Some(resp.map_err(|e| e.to_string()));
+                }
+                AppEvent::Written(change, resp) => app.apply(change, resp),
+                AppEvent::Lockouts(resp) => {
+                    app.lockouts_in_flight = false;
+                    app.lockouts = Some(resp.map_err(|e| e.to_string()));
+                    // Rendering an empty table clears the selection
+                    if app.table_state.selected().is_none() {
+                        app.table_state.select(Some(0));
+                    }
+                }
+            }
+        }
+
+        // Automatically resume scanning when returning to Monitor
+        if app.selected_tab == 0 && app.scan_paused {
+            dispatch(&scanner, &events_tx, |c| {
+                AppEvent::Written(Change::Pressed, c.press(Key::Scan, KeyAction::Press))
+            });
+            app.scan_paused = false;
             app.fetch_queue.clear();
         }
 
-        // Fetch Logic
-        if app.in_prg_mode {
-            if let Some(idx) = app.fetch_queue.pop_front() {
-                let resp = client.send_command(&format!("CIN,{}", idx)).unwrap_or_else(|e| format!("Err: {}", e));
-                if !app.update_channel(resp) {
-                     // Retry if failed (push to back)
-                     app.fetch_queue.push_back(idx);
-                }
+        if app.selected_tab == app.lockouts_tab() && app.lockouts.is_none() && !app.lockouts_in_flight {
+            app.lockouts_in_flight = true;
+            app.scan_paused = true;
+            dispatch(&scanner, &events_tx, |c| {
+                AppEvent::Lockouts(c.program().and_then(|mut s| s.get_lockouts(|_| {})))
+            });
+        }
+
+        if app.selected_tab == app.search_tab() && app.search.is_none() && !app.search_in_flight {
+            app.search_in_flight = true;
+            app.scan_paused = true;
+            dispatch(&scanner, &events_tx, |c| {
+                let search = c.program().and_then(|mut s| {
+                    Ok((s.get_search_ranges()?, s.get_search_groups()?))
+                });
+                AppEvent::Search(search)
+            });
+        }
+
+        if app.selected_tab == app.services_tab() && app.services.is_none() && !app.services_in_flight {
+            app.services_in_flight = true;
+            app.scan_paused = true;
+            dispatch(&scanner, &events_tx, |c| {
+                AppEvent::Services(c.program().and_then(|mut s| s.get_service_groups()))
+            });
+        }
+
+        // Fetch Logic, one chunk of channels in flight at a time
+        if app.selected_bank().is_some() {
+            if !app.fetch_in_flight && !app.fetch_queue.is_empty() {
+                let n = app.fetch_queue.len().min(FETCH_CHUNK);
+                let indices: Vec<u32> = app.fetch_queue.drain(..n).collect();
+                app.fetch_in_flight = true;
+                app.scan_paused = true;
+                dispatch(&scanner, &events_tx, move |c| {
+                    let channels = c
+                        .program()
+                        .and_then(|mut session| session.get_channels(&indices, |_, _| {}));
+                    AppEvent::Channels(indices, channels)
+                });
             }
         } else {
             // Poll scanner status only in Monitor mode
-            if app.selected_tab == 0 && last_poll.elapsed() >= Duration::from_millis(250) {
-                let resp = client.send_command("GLG").unwrap_or_else(|e| format!("Err: {}", e));
-                app.update_scan_status(resp);
+            if app.selected_tab == 0
+                && !app.poll_in_flight
+                && last_poll.elapsed() >= Duration::from_millis(250)
+            {
+                app.poll_in_flight = true;
+                dispatch(&scanner, &events_tx, |c| {
+                    let status = c.send_command("GLG");
+                    AppEvent::Status(status, c.display_status())
+                });
                 last_poll = Instant::now();
             }
         }
@@ -379,7 +704,7 @@ pub fn run(args: &ConsoleArgs) -> Result<(), Box<dyn std::error::Error>> {
                     [
                         Constraint::Length(3), // Tabs
                         Constraint::Min(0),    // Content
-                        Constraint::Length(3), // Help/Status
+                        Constraint::Length(4), // Help/Status
                     ]
                     .as_ref(),
                 )
@@ -402,6 +727,7 @@ pub fn run(args: &ConsoleArgs) -> Result<(), Box<dyn std::error::Error>> {
                             Constraint::Length(6),
                             Constraint::Length(6),
                             Constraint::Length(3), // Banks
+                            Constraint::Min(0),    // Close Call hits
                         ]
                         .as_ref(),
                     )
@@ -414,27 +740,60 @@ Volume:  {}
 Squelch: {}",
                     app.model, app.version, app.volume, app.squelch
                 );
+                let info_chunks = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Min(0), Constraint::Length(LINE_WIDTH as u16 + 2)])
+                    .split(monitor_chunks[0]);
                 let info_paragraph = Paragraph::new(info_text)
                     .block(Block::default().title("Scanner Info").borders(Borders::ALL));
-                f.render_widget(info_paragraph, monitor_chunks[0]);
-
-                let scan_text = format!(
-                    "Bank:      {}
-Frequency: {} MHz
-Channel:   {}",
-                    app.scan_status.bank,
-                    app.scan_status.frequency,
-                    app.scan_status.channel_name
-                );
+                f.render_widget(info_paragraph, info_chunks[0]);
+
+                // A mirror of the radio's own screen
+                let (lcd_title, lcd_lines) = match &app.display {
+                    Some(display) => (
+                        format!("LCD {:.<5}", "|".repeat(display.signal_level.into())),
+                        lcd_lines(display),
+                    ),
+                    None => ("LCD".to_string(), vec![]),
+                };
+                let lcd_paragraph = Paragraph::new(lcd_lines)
+                    .block(Block::default().title(lcd_title).borders(Borders::ALL));
+                f.render_widget(lcd_paragraph, info_chunks[1]);
+
+                let scan_text = match &app.scan_status {
+                    Some(status) => {
+                        let on_off = |b: bool| if b { "On" } else { "Off" };
+                        format!(
+                            "Bank:      {}
+Frequency: {} MHz {}
+Channel:   {} {}
+Signal:    {}  Att: {}  Mute: {}",
+                            status.bank(app.capabilities).map_or("-".to_string(), |b| b.to_string()),
+                            status.frequency,
+                            status.modulation,
+                            status.channel.map_or("-".to_string(), |c| c.to_string()),
+                            status.channel_name,
+                            status.signal_status,
+                            on_off(status.attenuator),
+                            on_off(status.muted)
+                        )
+                    }
+                    None => "Scanning...".to_string(),
+                };
 
-                let scan_style = if app.scan_status.signal_detected {
+                let scan_style = if app.scan_status.as_ref().is_some_and(|s| s.squelch_open) {
                     Style::default().bg(Color::Rgb(255, 165, 0)).fg(Color::Black)
                 } else {
                     Style::default()
                 };
 
+                let scan_title = if app.close_call_detector.is_active() {
+                    "Live Scan (Close Call hit)"
+                } else {
+                    "Live Scan"
+                };
                 let scan_paragraph = Paragraph::new(scan_text)
-                    .block(Block::default().title("Live Scan").borders(Borders::ALL).style(scan_style));
+                    .block(Block::default().title(scan_title).borders(Borders::ALL).style(scan_style));
                 f.render_widget(scan_paragraph, monitor_chunks[1]);
 
                 // Bank Status
@@ -452,28 +811,129 @@ Channel:   {}",
                     .block(Block::default().title("Active Banks (Press 1-0 to toggle)").borders(Borders::ALL));
                 f.render_widget(banks_paragraph, monitor_chunks[2]);
 
+                let hit_lines: Vec<Line> = app
+                    .close_call_hits
+                    .iter()
+                    .map(|(time, hit)| {
+                        Line::from(format!(
+                            "{}  {} MHz {}",
+                            time.format("%H:%M:%S"),
+                            hit.frequency,
+                            hit.modulation,
+                        ))
+                    })
+                    .collect();
+                let close_call_title = format!(
+                    "Close Call Hits (Mode: {}, 'c' to change)",
+                    app.close_call.as_ref().map_or("?".to_string(), |c| c.mode.to_string())
+                );
+                let hits_paragraph = Paragraph::new(hit_lines)
+                    .block(Block::default().title(close_call_title).borders(Borders::ALL));
+                f.render_widget(hits_paragraph, monitor_chunks[3]);
+
+            } else if app.selected_tab == app.lockouts_tab() {
+                let (title, rows) = match &app.lockouts {
+                    None => ("Global Lockouts (loading...)".to_string(), vec![]),
+                    Some(Err(e)) => (format!("Global Lockouts (read failed: {})", e), vec![]),
+                    Some(Ok(lockouts)) => (
+                        format!("Global Lockouts ({})", lockouts.len()),
+                        lockouts
+                            .iter()
+                            .map(|f| Row::new(vec![format!("{} MHz", f)]))
+                            .collect(),
+                    ),
+                };
+                let table = Table::new(rows, [Constraint::Length(14)])
+                    .header(Row::new(vec!["Frequency"]).style(Style::default().add_modifier(Modifier::BOLD)))
+                    .block(Block::default().borders(Borders::ALL).title(title))
+                    .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+                    .highlight_symbol(">> ");
+                f.render_stateful_widget(table, chunks[1], &mut app.table_state);
+            } else if app.selected_tab == app.search_tab() {
+                let (title, rows) = match &app.search {
+                    None => ("Custom Search (loading...)".to_string(), vec![]),
+                    Some(Err(e)) => (format!("Custom Search (read failed: {})", e), vec![]),
+                    Some(Ok((ranges, enabled))) => (
+                        "Custom Search".to_string(),
+                        ranges
+                            .iter()
+                            .zip(enabled)
+                            .map(|(range, &on)| {
+                                Row::new(vec![
+                                    range.index.to_string(),
+                                    if on { "On" } else { "Off" }.to_string(),
+                                    range.lower.to_string(),
+                                    range.upper.to_string(),
+                                ])
+                            })
+                            .collect(),
+                    ),
+                };
+                let table = Table::new(
+                    rows,
+                    [
+                        Constraint::Length(5),
+                        Constraint::Length(4),
+                        Constraint::Length(10),
+                        Constraint::Length(10),
+                    ],
+                )
+                .header(
+                    Row::new(vec!["Range", "On", "Lower", "Upper"])
+                        .style(Style::default().add_modifier(Modifier::BOLD)),
+                )
+                .block(Block::default().borders(Borders::ALL).title(title))
+                .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+                .highlight_symbol(">> ");
+                f.render_stateful_widget(table, chunks[1], &mut app.table_state);
+            } else if app.selected_tab == app.services_tab() {
+                let (title, rows) = match &app.services {
+                    None => ("Service Search (loading...)".to_string(), vec![]),
+                    Some(Err(e)) => (format!("Service Search (read failed: {})", e), vec![]),
+                    Some(Ok(groups)) => (
+                        "Service Search".to_string(),
+                        groups
+                            .iter()
+                            .map(|(group, on)| {
+                                Row::new(vec![
+                                    group.to_string(),
+                                    if on { "On" } else { "Off" }.to_string(),
+                                ])
+                            })
+                            .collect(),
+                    ),
+                };
+                let table = Table::new(rows, [Constraint::Length(16), Constraint::Length(4)])
+                    .header(
+                        Row::new(vec!["Service", "On"])
+                            .style(Style::default().add_modifier(Modifier::BOLD)),
+                    )
+                    .block(Block::default().borders(Borders::ALL).title(title))
+                    .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+                    .highlight_symbol(">> ");
+                f.render_stateful_widget(table, chunks[1], &mut app.table_state);
             } else {
                 // Bank View
                 let bank = app.selected_tab as u32;
-                let start_idx = (bank - 1) * 50 + 1;
-                let end_idx = bank * 50;
-                
                 let mut rows = Vec::new();
-                for i in start_idx..=end_idx {
+                for i in app.capabilities.bank_channels(bank) {
                     if let Some(chan) = &app.channels[i as usize] {
                         rows.push(Row::new(vec![
                             chan.index.to_string(),
-                            chan.name.clone(),
-                            chan.frequency.clone(),
-                            chan.modulation.clone(),
+                            chan.name.to_string(),
+                            if chan.frequency.is_zero() {
+                                String::new()
+                            } else {
+                                chan.frequency.to_string()
+                            },
+                            chan.modulation.to_string(),
+                            if chan.tone == Tone::None { String::new() } else { chan.tone.to_string() },
+                            chan.delay.seconds().to_string(),
+                            if chan.lockout { "L/O" } else { "" }.to_string(),
+                            if chan.priority { "Pri" } else { "" }.to_string(),
                         ]));
                     } else {
-                        rows.push(Row::new(vec![
-                            i.to_string(),
-                            "Loading...".to_string(),
-                            "".to_string(),
-                            "".to_string(),
-                        ]));
+                        rows.push(Row::new(vec![i.to_string(), "Loading...".to_string()]));
                     }
                 }
                 
@@ -484,41 +944,71 @@ Channel:   {}",
                         Constraint::Length(20),
                         Constraint::Length(10),
                         Constraint::Length(5),
+                        Constraint::Length(12),
+                        Constraint::Length(4),
+                        Constraint::Length(4),
+                        Constraint::Length(4),
                     ]
                 )
-                .header(Row::new(vec!["Idx", "Name", "Freq", "Mod"]).style(Style::default().add_modifier(Modifier::BOLD)))
+                .header(
+                    Row::new(vec!["Idx", "Name", "Freq", "Mod", "Tone", "Dly", "L/O", "Pri"])
+                        .style(Style::default().add_modifier(Modifier::BOLD)),
+                )
                 .block(Block::default().borders(Borders::ALL).title(format!("Bank {}", bank)))
                 .row_highlight_style(Style::default().add_modifier(Modifier::REVERSED))
                 .highlight_symbol(">> ");
                 f.render_stateful_widget(table, chunks[1], &mut app.table_state);
             }
 
-            let mode_str = if app.in_prg_mode { "Remote (PRG)" } else { "Monitor" };
-            let status_msg = if !app.fetch_queue.is_empty() {
-                format!("Loading... {} remaining ({})", app.fetch_queue.len(), mode_str)
+            let status_msg = if let Some(e) = &app.write_error {
+                format!("Write failed: {}", e)
+            } else if !app.fetch_queue.is_empty() {
+                format!("Loading... {} remaining", app.fetch_queue.len())
+            } else if let Some(e) = &app.fetch_error {
+                format!("Reading channels failed: {}", e)
             } else {
                 if app.selected_tab == 0 {
-                    app.scan_status.raw.clone()
+                    app.scan_raw.clone()
                 } else {
-                    format!("Ready ({})", mode_str)
+                    "Ready".to_string()
                 }
             };
 
             let help_keys = if app.selected_tab == 0 {
-                "Use Left/Right to switch tabs. 's': Scan, 'h': Hold, 'l': Set Squelch, '1-0': Toggle Banks, 'q': Quit."
+                "Use Left/Right to switch tabs. 's': Scan, 'h': Hold, 'o': Lock Out, 't': Tune, 'c': Close Call, 'l': Set Squelch, '1-0': Toggle Banks, 'q': Quit."
+            } else if app.selected_tab == app.lockouts_tab() {
+                "Use Left/Right to switch tabs. Up/Down or j/k to navigate. 'd': Unlock, 'q': Quit."
+            } else if app.selected_tab == app.search_tab() {
+                "Use Left/Right to switch tabs. Up/Down or j/k to navigate. 'e': Edit, Space: Enable/Disable, 'r': Start Search, 'q': Quit."
+            } else if app.selected_tab == app.services_tab() {
+                "Use Left/Right to switch tabs. Up/Down or j/k to navigate. Space: Enable/Disable, 'r': Start Search, 'q': Quit."
             } else {
                 "Use Left/Right to switch tabs. Up/Down or j/k to navigate. 'e': Edit, 'd': Delete, 'q': Quit."
             };
 
-            let help_text = Paragraph::new(format!("{}\nStatus: {}", help_keys, status_msg))
-                .block(Block::default().title("Help").borders(Borders::ALL));
+            let connection_state = *connection.borrow();
+            let help_style = if connection_state == ConnectionState::Connected {
+                Style::default()
+            } else {
+                Style::default().fg(Color::Red)
+            };
+            let help_text = Paragraph::new(format!(
+                "{}\nStatus: [{}] {}",
+                help_keys, connection_state, status_msg
+            ))
+            .block(Block::default().title("Help").borders(Borders::ALL).style(help_style));
              f.render_widget(help_text, chunks[2]);
 
             if app.input_mode == InputMode::ConfirmDelete {
                 let area = centered_rect(60, 20, f.area());
                 f.render_widget(Clear, area);
-                let idx = app.selected_channel_index();
-                let text = format!("\n  Are you sure you want to delete channel {}?\n\n  (y) Yes / (n) No", idx);
+                let question = match app.selected_lockout() {
+                    Some(frequency) if app.selected_tab == app.lockouts_tab() => {
+                        format!("remove the lockout on {} MHz", frequency)
+                    }
+                    _ => format!("delete channel {}", app.selected_channel_index()),
+                };
+                let text = format!("\n  Are you sure you want to {}?\n\n  (y) Yes / (n) No", question);
                 let block = Block::default().title("Confirm Delete").borders(Borders::ALL).style(Style::default().fg(Color::Red));
                 let paragraph = Paragraph::new(text).block(block);
                 f.render_widget(paragraph, area);
@@ -533,8 +1023,23 @@ Channel:   {}",
                 f.render_widget(paragraph, area);
             }
 
+            if let InputMode::Tune { frequency, error } = &app.input_mode {
+                let area = centered_rect(40, 20, f.area());
+                f.render_widget(Clear, area);
+                let mut lines = vec![
+                    Line::from(""),
+                    Line::from(format!("  Enter Frequency (MHz): {}", frequency)),
+                ];
+                if let Some(error) = error {
+                    lines.push(Line::styled(format!("  {}", error), Style::default().fg(Color::Red)));
+                }
+                let block = Block::default().title("Tune").borders(Borders::ALL).style(Style::default().fg(Color::Yellow));
+                let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: false });
+                f.render_widget(paragraph, area);
+            }
+
             if let InputMode::Editing(edit_state) = &app.input_mode {
-                let area = centered_rect(60, 40, f.area());
+                let area = centered_rect(60, 60, f.area());
                 f.render_widget(Clear, area);
 
                 let block = Block::default().title("Edit Channel").borders(Borders::ALL);
@@ -544,6 +1049,8 @@ Channel:   {}",
                     .direction(Direction::Vertical)
                     .margin(2)
                     .constraints([
+                        Constraint::Length(3),
+                        Constraint::Length(3),
                         Constraint::Length(3),
                         Constraint::Length(3),
                         Constraint::Min(0),
@@ -560,6 +1067,16 @@ Channel:   {}",
                 } else {
                     Style::default()
                 };
+                let mod_style = if edit_state.active_field == EditField::Modulation {
+                    Style::default().fg(Color::Yellow)
+                } else {
+                    Style::default()
+                };
+                let tone_style = if edit_state.active_field == EditField::Tone {
+                    Style::default().fg(Color::Yellow)
+                } else {
+                    Style::default()
+                };
 
                 let (freq_text, freq_display_style) = if edit_state.frequency.is_empty() {
                     ("118.100", Style::default().fg(Color::DarkGray))
@@ -575,197 +1092,447 @@ Channel:   {}",
                     .block(Block::default().title("Name").borders(Borders::ALL).style(name_style));
                 f.render_widget(name_input, inner_area[1]);
 
-                let help = Paragraph::new("Tab: Switch Field | Enter: Save | Esc: Cancel");
-                f.render_widget(help, inner_area[2]);
+                let mod_spans: Vec<Span> = Modulation::ALL
+                    .iter()
+                    .map(|&m| {
+                        if m == edit_state.channel.modulation {
+                            Span::styled(format!("[{}] ", m), Style::default().add_modifier(Modifier::BOLD))
+                        } else {
+                            Span::raw(format!(" {}  ", m))
+                        }
+                    })
+                    .collect();
+                let mod_input = Paragraph::new(Line::from(mod_spans))
+                    .block(Block::default().title("Modulation (Left/Right)").borders(Borders::ALL).style(mod_style));
+                f.render_widget(mod_input, inner_area[2]);
+
+                let tone_input = Paragraph::new(edit_state.tone.as_str())
+                    .block(Block::default().title("Tone (Left/Right or type, e.g. CTCSS 103.5, DCS 023)").borders(Borders::ALL).style(tone_style));
+                f.render_widget(tone_input, inner_area[3]);
+
+                let mut help_lines = vec![Line::from("Tab: Switch Field | Enter: Save | Esc: Cancel")];
+                if let Some(error) = &edit_state.error {
+                    help_lines.push(Line::styled(error.clone(), Style::default().fg(Color::Red)));
+                }
+                f.render_widget(Paragraph::new(help_lines).wrap(Wrap { trim: false }), inner_area[4]);
+            }
+
+            if let InputMode::EditingRange(range_state) = &app.input_mode {
+                let area = centered_rect(60, 40, f.area());
+                f.render_widget(Clear, area);
+
+                let block = Block::default()
+                    .title(format!("Edit Search Range {}", range_state.range.index))
+                    .borders(Borders::ALL);
+                f.render_widget(block, area);
+
+                let inner_area = Layout::default()
+                    .direction(Direction::Vertical)
+                    .margin(2)
+                    .constraints([Constraint::Length(3), Constraint::Length(3), Constraint::Min(0)])
+                    .split(area);
+
+                let active = Style::default().fg(Color::Yellow);
+                let (lower_style, upper_style) = if range_state.upper_active {
+                    (Style::default(), active)
+                } else {
+                    (active, Style::default())
+                };
+                let lower_input = Paragraph::new(range_state.lower.as_str())
+                    .block(Block::default().title("Lower Limit (MHz)").borders(Borders::ALL).style(lower_style));
+                f.render_widget(lower_input, inner_area[0]);
+                let upper_input = Paragraph::new(range_state.upper.as_str())
+                    .block(Block::default().title("Upper Limit (MHz)").borders(Borders::ALL).style(upper_style));
+                f.render_widget(upper_input, inner_area[1]);
+
+                let mut help_lines = vec![Line::from("Tab: Switch Field | Enter: Save | Esc: Cancel")];
+                if let Some(error) = &range_state.error {
+                    help_lines.push(Line::styled(error.clone(), Style::default().fg(Color::Red)));
+                }
+                f.render_widget(Paragraph::new(help_lines).wrap(Wrap { trim: false }), inner_area[2]);
             }
         })?;
 
         // Poll for input
-        let poll_timeout = if !app.fetch_queue.is_empty() {
-            Duration::from_millis(1) 
+        let poll_timeout = if !app.fetch_queue.is_empty() || app.fetch_in_flight {
+            Duration::from_millis(5)
         } else {
             Duration::from_millis(50)
         };
 
-        if event::poll(poll_timeout)? {
-            if let Event::Key(key) = event::read()? {
-                let idx = app.selected_channel_index();
-                match app.input_mode {
-                    InputMode::Normal => match key.code {
-                        KeyCode::Char('q') => break,
-                        KeyCode::Right => app.next_tab(),
-                        KeyCode::Left => app.previous_tab(),
-                        KeyCode::Down | KeyCode::Char('j') if app.selected_tab > 0 => {
-                            app.next_channel();
+        if event::poll(poll_timeout)?
+            && let Event::Key(key) = event::read()?
+        {
+            let idx = app.selected_channel_index();
+            match app.input_mode {
+                InputMode::Normal => match key.code {
+                    KeyCode::Char('q') => break,
+                    KeyCode::Right => app.next_tab(),
+                    KeyCode::Left => app.previous_tab(),
+                    KeyCode::Down | KeyCode::Char('j') if app.selected_tab > 0 => {
+                        app.next_channel();
+                    }
+                    KeyCode::Up | KeyCode::Char('k') if app.selected_tab > 0 => {
+                        app.previous_channel();
+                    }
+                    KeyCode::Char('d') if app.selected_bank().is_some() => {
+                        app.input_mode = InputMode::ConfirmDelete;
+                    }
+                    KeyCode::Char('d')
+                        if app.selected_tab == app.lockouts_tab() && app.selected_lockout().is_some() =>
+                    {
+                        app.input_mode = InputMode::ConfirmDelete;
+                    }
+                    KeyCode::Char('e') | KeyCode::Enter if app.selected_tab == app.search_tab() => {
+                        if let Some((range, _)) = app.selected_search_range() {
+                            app.input_mode = InputMode::EditingRange(RangeEditState {
+                                range,
+                                lower: range.lower.to_string(),
+                                upper: range.upper.to_string(),
+                                upper_active: false,
+                                error: None,
+                            });
                         }
-                        KeyCode::Up | KeyCode::Char('k') if app.selected_tab > 0 => {
-                            app.previous_channel();
+                    }
+                    KeyCode::Char(' ') if app.selected_tab == app.search_tab() => {
+                        if let Some(Ok((_, enabled))) = &app.search {
+                            let row = app.table_state.selected().unwrap_or(0);
+                            let mut groups = *enabled;
+                            groups[row] = !groups[row];
+                            // The radio needs at least one range to search
+                            if groups.contains(&true) {
+                                dispatch(&scanner, &events_tx, move |c| {
+                                    let resp = c.program().and_then(|mut s| s.set_search_groups(&groups));
+                                    AppEvent::Written(Change::SearchGroups(groups), resp)
+                                });
+                                app.scan_paused = true;
+                            }
                         }
-                        KeyCode::Char('d') if app.selected_tab > 0 => {
-                            app.input_mode = InputMode::ConfirmDelete;
+                    }
+                    KeyCode::Char('r') if app.selected_tab == app.search_tab() => {
+                        dispatch(&scanner, &events_tx, |c| {
+                            AppEvent::Written(Change::Pressed, c.start_custom_search())
+                        });
+                        // Watch the search on the Monitor tab, without the
+                        // usual resume to scanning
+                        app.scan_paused = false;
+                        app.selected_tab = 0;
+                    }
+                    KeyCode::Char(' ') if app.selected_tab == app.services_tab() => {
+                        if let Some(Ok(groups)) = &app.services {
+                            let group = ServiceGroup::ALL[app.table_state.selected().unwrap_or(0)];
+                            let mut toggled = *groups;
+                            toggled.set(group, !groups.contains(group));
+                            // The radio needs at least one group to search
+                            if toggled.iter().any(|(_, on)| on) {
+                                dispatch(&scanner, &events_tx, move |c| {
+                                    let resp = c.program().and_then(|mut s| s.set_service_groups(&toggled));
+                                    AppEvent::Written(Change::ServiceGroups(toggled), resp)
+                                });
+                                app.scan_paused = true;
+                            }
                         }
-                        KeyCode::Char('e') | KeyCode::Enter if app.selected_tab > 0 => {
-                            let (freq, name) = if let Some(chan) = &app.channels[idx as usize] {
-                                (chan.frequency.clone(), chan.name.clone())
-                            } else {
-                                ("".to_string(), "".to_string())
-                            };
-                            app.input_mode = InputMode::Editing(EditState {
-                                frequency: freq,
-                                name: name,
-                                active_field: EditField::Frequency,
+                    }
+                    KeyCode::Char('r') if app.selected_tab == app.services_tab() => {
+                        dispatch(&scanner, &events_tx, |c| {
+                            AppEvent::Written(Change::Pressed, c.start_service_search())
+                        });
+                        app.scan_paused = false;
+                        app.selected_tab = 0;
+                    }
+                    KeyCode::Char('e') | KeyCode::Enter if app.selected_bank().is_some() => {
+                        let channel = app.channels[idx as usize]
+                            .clone()
+                            .unwrap_or_else(|| ChannelInfo::empty(idx));
+                        let freq = if channel.is_empty() {
+                            "".to_string()
+                        } else {
+                            channel.frequency.to_string()
+                        };
+                        app.input_mode = InputMode::Editing(EditState {
+                            frequency: freq,
+                            name: channel.name.to_string(),
+                            tone: channel.tone.to_string(),
+                            channel,
+                            active_field: EditField::Frequency,
+                            error: None,
+                        });
+                    }
+                    KeyCode::Char('s') if app.selected_tab == 0 => {
+                        dispatch(&scanner, &events_tx, |c| {
+                            AppEvent::Written(Change::Pressed, c.press(Key::Scan, KeyAction::Press))
+                        });
+                    }
+                    KeyCode::Char('t') if app.selected_tab == 0 => {
+                        app.input_mode = InputMode::Tune { frequency: String::new(), error: None };
+                    }
+                    KeyCode::Char('l') if app.selected_tab == 0 => {
+                        app.squelch_input.clear();
+                        app.input_mode = InputMode::SetSquelch;
+                    }
+                    KeyCode::Char('h') if app.selected_tab == 0 => {
+                        dispatch(&scanner, &events_tx, |c| {
+                            AppEvent::Written(Change::Pressed, c.press(Key::Hold, KeyAction::Press))
+                        });
+                    }
+                    KeyCode::Char('c') if app.selected_tab == 0 => {
+                        if let Some(settings) = &app.close_call {
+                            let modes = CloseCallMode::ALL;
+                            let next = modes.iter().position(|&m| m == settings.mode).map_or(0, |i| i + 1);
+                            let mut settings = settings.clone();
+                            settings.mode = modes[next % modes.len()];
+                            dispatch(&scanner, &events_tx, move |c| {
+                                let resp = c.set_close_call(&settings);
+                                AppEvent::Written(Change::CloseCall(settings), resp)
                             });
                         }
-                        KeyCode::Char('s') if app.selected_tab == 0 => {
-                            let _ = client.send_command("KEY,S,P");
+                    }
+                    KeyCode::Char('o') if app.selected_tab == 0 => {
+                        // Like the radio's L/O key: lock out what is being
+                        // received and carry on scanning
+                        match app.receiving() {
+                            Ok(frequency) => dispatch(&scanner, &events_tx, move |c| {
+                                let resp = c.program().and_then(|s| s.resume_scan().lock_out(frequency));
+                                AppEvent::Written(Change::LockedOut, resp)
+                            }),
+                            Err(e) => app.write_error = Some(e),
                         }
-                        KeyCode::Char('l') if app.selected_tab == 0 => {
-                            app.squelch_input.clear();
-                            app.input_mode = InputMode::SetSquelch;
+                    }
+                    KeyCode::Char(c) if app.selected_tab == 0 && c.is_ascii_digit() => {
+                        if let Some(digit) = c.to_digit(10) {
+                            // 1->0, 2->1, ... 0->9
+                            let bank_idx = if digit == 0 { 9 } else { digit - 1 } as usize;
+                            if bank_idx < app.banks.len() {
+                                let mut banks = app.banks.clone();
+                                banks[bank_idx] = !banks[bank_idx];
+                                dispatch(&scanner, &events_tx, move |c| {
+                                    let resp = c
+                                        .program()
+                                        .and_then(|s| s.resume_scan().set_scan_banks(&banks));
+                                    AppEvent::Written(Change::ScanBanks(banks), resp)
+                                });
+                            }
                         }
-                        KeyCode::Char('h') if app.selected_tab == 0 => {
-                            let _ = client.send_command("KEY,H,P");
+                    }
+                    _ => {}
+                },
+                InputMode::ConfirmDelete => match key.code {
+                    KeyCode::Char('y') if app.selected_tab == app.lockouts_tab() => {
+                        if let Some(frequency) = app.selected_lockout() {
+                            dispatch(&scanner, &events_tx, move |c| {
+                                let resp = c.program().and_then(|mut s| s.unlock(frequency));
+                                AppEvent::Written(Change::Unlocked(frequency), resp)
+                            });
+                            app.scan_paused = true;
                         }
-                        KeyCode::Char(c) if app.selected_tab == 0 && c.is_digit(10) => {
-                            if let Some(digit) = c.to_digit(10) {
-                                // 1->0, 2->1, ... 0->9
-                                let bank_idx = if digit == 0 { 9 } else { digit - 1 } as usize;
-                                if bank_idx < 10 {
-                                    app.banks[bank_idx] = !app.banks[bank_idx];
-                                    let scg_cmd = app.get_scg_string();
-                                    // Apply change
-                                    let _ = client.send_command("PRG");
-                                    let _ = client.send_command(&scg_cmd);
-                                    let _ = client.send_command("EPG");
-                                    let _ = client.send_command("KEY,S,P");
-                                }
-                            }
+                        app.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Char('y') => {
+                        dispatch(&scanner, &events_tx, move |c| {
+                            let resp = c.program().and_then(|mut s| s.delete_channel(idx));
+                            AppEvent::Written(Change::ChannelDeleted(idx), resp)
+                        });
+                        app.scan_paused = true;
+                        app.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Char('n') | KeyCode::Esc => {
+                        app.input_mode = InputMode::Normal;
+                    }
+                    _ => {}
+                },
+                InputMode::SetSquelch => match key.code {
+                    KeyCode::Char(c) if c.is_ascii_digit() => {
+                        if app.squelch_input.len() < 2 {
+                            app.squelch_input.push(c);
                         }
-                        _ => {}
-                    },
-                    InputMode::ConfirmDelete => match key.code {
-                        KeyCode::Char('y') => {
-                            let cmd = format!("DCH,{}", idx);
-                            let _ = client.send_command(&cmd);
-                            app.channels[idx as usize] = None;
-                            app.fetch_queue.push_back(idx);
-                            app.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Backspace => {
+                        app.squelch_input.pop();
+                    }
+                    KeyCode::Enter => {
+                        if let Ok(lvl) = app.squelch_input.parse::<u8>()
+                            && lvl <= 15
+                        {
+                            dispatch(&scanner, &events_tx, move |c| {
+                                AppEvent::Squelch(lvl, c.set_squelch(lvl))
+                            });
                         }
-                        KeyCode::Char('n') | KeyCode::Esc => {
+                        app.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Esc => {
+                        app.input_mode = InputMode::Normal;
+                    }
+                    _ => {}
+                },
+                InputMode::Tune { ref mut frequency, ref mut error } => match key.code {
+                    KeyCode::Char(c) if c.is_ascii_digit() || c == '.' => {
+                        frequency.push(c);
+                    }
+                    KeyCode::Backspace => {
+                        frequency.pop();
+                    }
+                    KeyCode::Enter => match frequency
+                        .parse::<Frequency>()
+                        .and_then(|f| app.capabilities.check_frequency(f).map(|_| f))
+                    {
+                        Ok(frequency) => {
+                            dispatch(&scanner, &events_tx, move |c| {
+                                AppEvent::Written(Change::Pressed, c.press_keys(&keys::tune(frequency)))
+                            });
                             app.input_mode = InputMode::Normal;
                         }
-                        _ => {}
+                        Err(e) => *error = Some(e.to_string()),
                     },
-                    InputMode::SetSquelch => match key.code {
-                        KeyCode::Char(c) if c.is_digit(10) => {
-                            if app.squelch_input.len() < 2 {
-                                app.squelch_input.push(c);
-                            }
-                        }
-                        KeyCode::Backspace => {
-                            app.squelch_input.pop();
+                    KeyCode::Esc => {
+                        app.input_mode = InputMode::Normal;
+                    }
+                    _ => {}
+                },
+                InputMode::Editing(ref mut edit_state) => match key.code {
+                    KeyCode::Esc => {
+                        app.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Tab => {
+                        edit_state.active_field = match edit_state.active_field {
+                            EditField::Frequency => EditField::Name,
+                            EditField::Name => EditField::Modulation,
+                            EditField::Modulation => EditField::Tone,
+                            EditField::Tone => EditField::Frequency,
+                        };
+                    }
+                    KeyCode::Left | KeyCode::Right
+                        if edit_state.active_field == EditField::Modulation =>
+                    {
+                        let n = Modulation::ALL.len();
+                        let step = if key.code == KeyCode::Right { 1 } else { n - 1 };
+                        let current = Modulation::ALL
+                            .iter()
+                            .position(|&m| m == edit_state.channel.modulation)
+                            .unwrap_or(0);
+                        edit_state.channel.modulation = Modulation::ALL[(current + step) % n];
+                    }
+                    KeyCode::Left | KeyCode::Right if edit_state.active_field == EditField::Tone => {
+                        let tones: Vec<Tone> = Tone::all().collect();
+                        let n = tones.len();
+                        let step = if key.code == KeyCode::Right { 1 } else { n - 1 };
+                        // Start from whatever has been typed, if it is a tone
+                        let current = edit_state
+                            .tone
+                            .parse::<Tone>()
+                            .ok()
+                            .and_then(|t| tones.iter().position(|&x| x == t))
+                            .unwrap_or(0);
+                        edit_state.tone = tones[(current + step) % n].to_string();
+                    }
+                    KeyCode::Char(c) => match edit_state.active_field {
+                        EditField::Frequency => edit_state.frequency.push(c),
+                        EditField::Name => edit_state.name.push(c),
+                        EditField::Tone => edit_state.tone.push(c),
+                        EditField::Modulation => {}
+                    },
+                    KeyCode::Backspace => match edit_state.active_field {
+                        EditField::Frequency => {
+                            edit_state.frequency.pop();
                         }
-                        KeyCode::Enter => {
-                            if let Ok(lvl) = app.squelch_input.parse::<u8>() {
-                                if lvl <= 15 {
-                                    if client.set_squelch(lvl).is_ok() {
-                                        app.squelch = format!("SQL,{}", lvl);
-                                    }
-                                }
-                            }
-                            app.input_mode = InputMode::Normal;
+                        EditField::Name => {
+                            edit_state.name.pop();
                         }
-                        KeyCode::Esc => {
-                            app.input_mode = InputMode::Normal;
+                        EditField::Tone => {
+                            edit_state.tone.pop();
                         }
-                        _ => {}
+                        EditField::Modulation => {}
                     },
-                    InputMode::Editing(ref mut edit_state) => match key.code {
-                        KeyCode::Esc => {
-                            app.input_mode = InputMode::Normal;
-                        }
-                        KeyCode::Tab => {
-                            edit_state.active_field = match edit_state.active_field {
-                                EditField::Frequency => EditField::Name,
-                                EditField::Name => EditField::Frequency,
-                            };
-                        }
-                        KeyCode::Char(c) => match edit_state.active_field {
-                            EditField::Frequency => edit_state.frequency.push(c),
-                            EditField::Name => edit_state.name.push(c),
-                        },
-                        KeyCode::Backspace => match edit_state.active_field {
-                            EditField::Frequency => {
-                                edit_state.frequency.pop();
+                    KeyCode::Enter => {
+                        let frequency = match edit_state
+                            .frequency
+                            .parse::<Frequency>()
+                            .and_then(|f| app.capabilities.check_frequency(f).map(|_| f))
+                        {
+                            Ok(frequency) => frequency,
+                            Err(e) => {
+                                edit_state.error = Some(e.to_string());
+                                continue;
                             }
-                            EditField::Name => {
-                                edit_state.name.pop();
+                        };
+
+                        let tone = match edit_state.tone.parse::<Tone>() {
+                            Ok(tone) => tone,
+                            Err(e) => {
+                                edit_state.error = Some(e.to_string());
+                                continue;
                             }
-                        },
-                        KeyCode::Enter => {
-                            let raw_freq = if edit_state.frequency.contains('.') {
-                                let parts: Vec<&str> = edit_state.frequency.split('.').collect();
-                                let mut mhz = parts[0].to_string();
-                                let mut khz = if parts.len() > 1 {
-                                    parts[1].to_string()
-                                } else {
-                                    "".to_string()
-                                };
-
-                                // Pad MHz to 4 digits with leading zeros
-                                while mhz.len() < 4 {
-                                    mhz.insert(0, '0');
-                                }
-                                if mhz.len() > 4 {
-                                    mhz.truncate(4);
-                                }
-
-                                // Pad KHz to 4 digits with trailing zeros
-                                while khz.len() < 4 {
-                                    khz.push('0');
-                                }
-                                if khz.len() > 4 {
-                                    khz.truncate(4);
-                                }
-                                format!("{}{}", mhz, khz)
-                            } else if edit_state.frequency.len() >= 7 {
-                                // Assume raw format if long and no dot
-                                let mut f = edit_state.frequency.clone();
-                                while f.len() < 8 {
-                                    f.insert(0, '0');
-                                }
-                                if f.len() > 8 {
-                                    f.truncate(8);
-                                }
-                                f
-                            } else if !edit_state.frequency.is_empty() {
-                                // Short input without dot, assume MHz
-                                let mut mhz = edit_state.frequency.clone();
-                                while mhz.len() < 4 {
-                                    mhz.insert(0, '0');
-                                }
-                                format!("{}0000", mhz)
-                            } else {
-                                "".to_string()
-                            };
-
-                            let cmd =
-                                format!("CIN,{},{},{},AM,0,0,0,0", idx, edit_state.name, raw_freq);
-                            let _ = client.send_command(&cmd);
-
-                            // Update local state
-                            app.channels[idx as usize] = Some(Channel {
-                                index: idx,
-                                name: edit_state.name.clone(),
-                                frequency: edit_state.frequency.clone(),
-                                modulation: "AM".to_string(),
-                            });
+                        };
 
-                            app.input_mode = InputMode::Normal;
+                        let name = match ChannelName::transliterate(&edit_state.name) {
+                            Ok(name) => name,
+                            Err(e) => {
+                                edit_state.error = Some(e.to_string());
+                                continue;
+                            }
+                        };
+
+                        let mut channel = edit_state.channel.clone();
+                        channel.frequency = frequency;
+                        channel.tone = tone;
+                        channel.name = name;
+                        dispatch(&scanner, &events_tx, move |c| {
+                            let resp = c.program().and_then(|mut s| s.set_channel(&channel));
+                            AppEvent::Written(Change::Channel(channel), resp)
+                        });
+                        app.scan_paused = true;
+                        app.input_mode = InputMode::Normal;
+                    }
+                    _ => {}
+                },
+                InputMode::EditingRange(ref mut range_state) => match key.code {
+                    KeyCode::Esc => {
+                        app.input_mode = InputMode::Normal;
+                    }
+                    KeyCode::Tab => {
+                        range_state.upper_active = !range_state.upper_active;
+                    }
+                    KeyCode::Char(c) => {
+                        if range_state.upper_active {
+                            range_state.upper.push(c);
+                        } else {
+                            range_state.lower.push(c);
                         }
-                        _ => {}
-                    },
-                }
+                    }
+                    KeyCode::Backspace => {
+                        if range_state.upper_active {
+                            range_state.upper.pop();
+                        } else {
+                            range_state.lower.pop();
+                        }
+                    }
+                    KeyCode::Enter => {
+                        let limits = range_state
+                            .lower
+                            .parse::<Frequency>()
+                            .and_then(|lower| Ok((lower, range_state.upper.parse::<Frequency>()?)));
+                        let range = match limits {
+                            Ok((lower, upper)) => SearchRange { lower, upper, ..range_state.range },
+                            Err(e) => {
+                                range_state.error = Some(e.to_string());
+                                continue;
+                            }
+                        };
+                        let checked =
+                            range.to_command().and_then(|_| range.check_bands(app.capabilities));
+                        if let Err(e) = checked {
+                            range_state.error = Some(e.to_string());
+                            continue;
+                        }
+
+                        dispatch(&scanner, &events_tx, move |c| {
+                            let resp = c.program().and_then(|mut s| s.set_search_range(&range));
+                            AppEvent::Written(Change::SearchRange(range), resp)
+                        });
+                        app.scan_paused = true;
+                        app.input_mode = InputMode::Normal;
+                    }
+                    _ => {}
+                },
             }
         }
     }
@@ -785,87 +1552,140 @@ Channel:   {}",
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_parse_glg_response() {
-        let mut app = App {
+    fn test_app() -> App {
+        App {
             model: "".into(),
+            capabilities: &model::UNKNOWN,
             version: "".into(),
             volume: "".into(),
             squelch: "".into(),
             squelch_input: "".into(),
-            scan_status: ScanStatus::default(),
+            scan_status: None,
+            scan_status_current: false,
+            scan_raw: String::new(),
+            display: None,
+            close_call: None,
+            close_call_detector: CloseCallDetector::default(),
+            close_call_hits: VecDeque::new(),
             tabs: vec![],
             selected_tab: 0,
-            channels: vec![],
+            channels: vec![None; usize::from(model::UNKNOWN.channel_count) + 1],
             fetch_queue: VecDeque::new(),
-            in_prg_mode: false,
-            banks: vec![true; 10],
+            fetch_in_flight: false,
+            fetch_failures: HashMap::new(),
+            fetch_error: None,
+            poll_in_flight: false,
+            scan_paused: false,
+            banks: vec![true; model::UNKNOWN.bank_count],
+            lockouts: None,
+            lockouts_in_flight: false,
+            search: None,
+            search_in_flight: false,
+            services: None,
+            services_in_flight: false,
+            write_error: None,
             input_mode: InputMode::Normal,
             table_state: TableState::default(),
-        };
+        }
+    }
+
+    #[test]
+    fn test_parse_glg_response() {
+        let mut app = test_app();
 
         // Example from SCANNER-COMMANDS.md: GLG,01239750,AM,,0,,,BHX RADAR,1,0,,52,
         app.update_scan_status("GLG,01239750,AM,,0,,,BHX RADAR,1,0,,52,".to_string());
         
-        assert_eq!(app.scan_status.frequency, "123.9750");
-        assert_eq!(app.scan_status.bank, "2");
-        assert_eq!(app.scan_status.channel_name, "BHX RADAR");
+        let status = app.scan_status.as_ref().unwrap();
+        assert_eq!(status.frequency.to_string(), "123.9750");
+        assert_eq!(status.bank(app.capabilities), Some(2));
+        assert_eq!(status.channel_name, "BHX RADAR");
     }
 
     #[test]
     fn test_parse_glg_low_frequency() {
-        let mut app = App {
-            model: "".into(),
-            version: "".into(),
-            volume: "".into(),
-            squelch: "".into(),
-            squelch_input: "".into(),
-            scan_status: ScanStatus::default(),
-            tabs: vec![],
-            selected_tab: 0,
-            channels: vec![],
-            fetch_queue: VecDeque::new(),
-            in_prg_mode: false,
-            banks: vec![true; 10],
-            input_mode: InputMode::Normal,
-            table_state: TableState::default(),
-        };
+        let mut app = test_app();
 
         // Test with a frequency < 100MHz (padding check)
         app.update_scan_status("GLG,00881000,FM,,0,,,BBC R2,1,0,,1,".to_string());
         
-        assert_eq!(app.scan_status.frequency, "88.1000");
-        assert_eq!(app.scan_status.bank, "1");
-        assert_eq!(app.scan_status.channel_name, "BBC R2");
+        let status = app.scan_status.as_ref().unwrap();
+        assert_eq!(status.frequency.to_string(), "88.1000");
+        assert_eq!(status.bank(app.capabilities), Some(1));
+        assert_eq!(status.channel_name, "BBC R2");
     }
 
     #[test]
     fn test_parse_glg_signal_detected() {
-        let mut app = App {
-            model: "".into(),
-            version: "".into(),
-            volume: "".into(),
-            squelch: "".into(),
-            squelch_input: "".into(),
-            scan_status: ScanStatus::default(),
-            tabs: vec![],
-            selected_tab: 0,
-            channels: vec![],
-            fetch_queue: VecDeque::new(),
-            in_prg_mode: false,
-            banks: vec![true; 10],
-            input_mode: InputMode::Normal,
-            table_state: TableState::default(),
-        };
+        let mut app = test_app();
 
         // Case 1: Signal Detected (Index 8 = 1)
         // Example: GLG,01239750,AM,,0,,,BHX RADAR,1,0,,52,
         app.update_scan_status("GLG,01239750,AM,,0,,,BHX RADAR,1,0,,52,".to_string());
-        assert!(app.scan_status.signal_detected);
-        assert_eq!(app.scan_status.channel_name, "BHX RADAR");
+        assert!(app.scan_status.as_ref().unwrap().squelch_open);
+        assert_eq!(app.scan_status.as_ref().unwrap().channel_name, "BHX RADAR");
 
         // Case 2: No Signal (Index 8 = 0)
         app.update_scan_status("GLG,01239750,AM,,0,,,QUIET,0,0,,52,".to_string());
-        assert!(!app.scan_status.signal_detected);
+        assert!(!app.scan_status.as_ref().unwrap().squelch_open);
+
+        // Case 3: Scanning, no channel to report
+        app.update_scan_status("GLG,,,,,,,,,,,,".to_string());
+        assert!(app.scan_status.is_none());
+
+        // An error polling keeps the last status
+        app.update_scan_status("GLG,01239750,AM,,0,,,QUIET,0,0,,52,".to_string());
+        assert!(app.receiving().is_err());
+        app.update_scan_status("GLG,01239750,AM,,0,,,BHX RADAR,1,0,,52,".to_string());
+        assert_eq!(app.receiving().unwrap().to_string(), "123.9750");
+        app.update_scan_status("Err: Timeout".to_string());
+        assert!(app.scan_status.is_some());
+        assert_eq!(app.scan_raw, "Err: Timeout");
+        // but it is too old to lock out
+        assert!(app.receiving().is_err());
+    }
+
+    #[test]
+    fn test_rejected_writes_leave_state_alone() {
+        let mut app = test_app();
+        let mut banks = app.banks.clone();
+        banks[2] = false;
+
+        app.apply(Change::ScanBanks(banks.clone()), Err(ScannerError::NotNow("PRG".into())));
+        assert_eq!(app.banks, [true; 10]);
+        assert!(app.write_error.is_some());
+
+        let mut channel = ChannelInfo::empty(7);
+        channel.frequency = "145.5".parse().unwrap();
+        app.apply(Change::Channel(channel.clone()), Err(ScannerError::Timeout("CIN".into())));
+        assert_eq!(app.channels[7], None);
+
+        app.apply(Change::Channel(channel.clone()), Ok(()));
+        app.apply(Change::ScanBanks(banks.clone()), Ok(()));
+        assert_eq!(app.channels[7], Some(channel));
+        assert_eq!(app.banks, banks);
+        assert_eq!(app.write_error, None);
+    }
+
+    #[test]
+    fn test_failed_reads_are_retried_a_few_times() {
+        let mut app = test_app();
+        app.selected_tab = 1;
+
+        let timeout = || ScannerError::Timeout("CIN,1".into());
+        for _ in 1..FETCH_ATTEMPTS {
+            app.fetch_failed(vec![1, 2], timeout());
+            assert_eq!(app.fetch_queue.drain(..).collect::<Vec<_>>(), [1, 2]);
+        }
+        // Given up on after the last attempt
+        app.fetch_failed(vec![1, 2], timeout());
+        assert!(app.fetch_queue.is_empty());
+        assert_eq!(app.fetch_error.as_deref(), Some("Timed out waiting for a response to CIN,1"));
+
+        // An error that won't clear up isn't retried at all
+        let mut app = test_app();
+        app.selected_tab = 1;
+        app.fetch_failed(vec![3], ScannerError::InvalidArgument("Channel index".into()));
+        assert!(app.fetch_queue.is_empty());
     }
 }
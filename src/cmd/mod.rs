@@ -1,6 +1,9 @@
+pub mod backup;
 pub mod cli;
 pub mod console;
 pub mod serve;
+pub mod settings;
+pub mod simulate;
 
 pub mod prelude {
     pub(crate) use clap::Parser;
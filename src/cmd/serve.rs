@@ -1,7 +1,8 @@
+use std::path::PathBuf;
 use clap::Args;
-use std::sync::{Arc, Mutex};
 use crate::server;
 use crate::scanner::ScannerClient;
+use crate::scanner::actor::ScannerHandle;
 use tower_http::cors::{Any, CorsLayer};
 use ubc125_grpc::ubc125::v1::system_info_service_server::SystemInfoServiceServer;
 use ubc125_grpc::ubc125::v1::scanner_control_service_server::ScannerControlServiceServer;
@@ -10,8 +11,14 @@ use ubc125_grpc::ubc125::v1::scanner_control_service_server::ScannerControlServi
 pub struct ServeArgs {
     #[arg(short, long, default_value_t = String::from("127.0.0.1:50051"))]
     pub server_addr: String,
-    #[arg(short, long, default_value_t = String::from("/dev/ttyACM0"))]
+    /// Serial device path, tcp://host:port for a serial-over-TCP bridge,
+    /// replay:FILE to play back a capture, or auto to find a USB-connected
+    /// scanner
+    #[arg(short, long, default_value_t = String::from("auto"))]
     pub device: String,
+    /// Record every exchange with the scanner to this JSONL file
+    #[arg(short, long)]
+    pub record: Option<PathBuf>,
 }
 
 // grpcurl -plaintext localhost:50051 ubc125.v1.SystemInfoService/GetModelInfo
@@ -22,9 +29,12 @@ pub async fn run(args: &ServeArgs) -> Result<(), Box<dyn std::error::Error>> {
         .register_encoded_file_descriptor_set(ubc125_grpc::ubc125::v1::FILE_DESCRIPTOR_SET)
         .build_v1()?;
 
-    let client = ScannerClient::new(&args.device)?;
+    let mut client = ScannerClient::open(&args.device)?;
+    if let Some(path) = &args.record {
+        client.record_to(path)?;
+    }
     let scanner_server = server::ScannerServer {
-        client: Arc::new(Mutex::new(client)),
+        scanner: ScannerHandle::spawn(client),
     };
 
     println!("Starting server at {}", args.server_addr);
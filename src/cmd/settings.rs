@@ -0,0 +1,54 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use clap::Args;
+use crate::scanner::ScannerClient;
+use crate::scanner::settings::ScannerSettings;
+
+#[derive(Args)]
+pub struct SettingsArgs {
+    /// Serial device path, tcp://host:port for a serial-over-TCP bridge,
+    /// replay:FILE to play back a capture, or auto to find a USB-connected
+    /// scanner
+    #[arg(short, long, default_value_t = String::from("auto"))]
+    pub device: String,
+    /// JSON file of settings to write, in the form printed when reading
+    #[arg(short, long)]
+    pub input: Option<PathBuf>,
+    /// File to save the radio's settings to, as JSON, rather than printing
+    /// them
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+// ubc125 settings -o settings.json
+// ubc125 settings -i settings.json
+
+pub fn run(args: &SettingsArgs) -> Result<(), Box<dyn std::error::Error>> {
+    // Parse the file before touching the radio, so a bad one writes nothing
+    let wanted = match &args.input {
+        Some(path) => Some(serde_json::from_reader::<_, ScannerSettings>(BufReader::new(
+            File::open(path)?,
+        ))?),
+        None => None,
+    };
+
+    let mut client = ScannerClient::open(&args.device)?;
+    let mut session = client.program()?;
+    if let Some(settings) = wanted {
+        session.set_settings(&settings)?;
+    }
+    let settings = session.get_settings()?;
+
+    match &args.output {
+        Some(path) => {
+            let mut file = BufWriter::new(File::create(path)?);
+            serde_json::to_writer_pretty(&mut file, &settings)?;
+            writeln!(file)?;
+            file.flush()?;
+            println!("Saved settings to {}", path.display());
+        }
+        None => println!("{}", serde_json::to_string_pretty(&settings)?),
+    }
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use std::fs::OpenOptions;
+use std::io::{Read, Write};
+use std::os::fd::AsRawFd;
+use std::time::{Duration, Instant};
+
+use clap::Args;
+use nix::fcntl::OFlag;
+use nix::poll::{poll, PollFd, PollFlags};
+use nix::pty::{grantpt, posix_openpt, ptsname_r, unlockpt};
+use nix::sys::termios::{cfmakeraw, tcgetattr, tcsetattr, SetArg};
+use crate::simulator::Simulator;
+
+#[derive(Args)]
+pub struct SimulateArgs {
+    /// Milliseconds between simulated scan steps
+    #[arg(short, long, default_value_t = 100)]
+    pub tick_ms: u64,
+}
+
+// ubc125 simulate
+// ubc125 console -c /dev/pts/N
+
+pub fn run(args: &SimulateArgs) -> Result<(), Box<dyn std::error::Error>> {
+    let mut master = posix_openpt(OFlag::O_RDWR | OFlag::O_NOCTTY)?;
+    grantpt(&master)?;
+    unlockpt(&master)?;
+    let slave_path = ptsname_r(&master)?;
+
+    // Hold the slave open so the master doesn't see a hangup between
+    // clients, and put it in raw mode so commands aren't echoed back.
+    let slave = OpenOptions::new().read(true).write(true).open(&slave_path)?;
+    let mut termios = tcgetattr(slave.as_raw_fd())?;
+    cfmakeraw(&mut termios);
+    tcsetattr(slave.as_raw_fd(), SetArg::TCSANOW, &termios)?;
+
+    println!("Simulated BC125AT listening on {}", slave_path);
+    println!("e.g. ubc125 console -c {}", slave_path);
+
+    let mut sim = Simulator::new();
+    let tick = Duration::from_millis(args.tick_ms);
+    let mut last_tick = Instant::now();
+    let mut line = Vec::new();
+    let mut buf = [0u8; 256];
+
+    loop {
+        let mut fds = [PollFd::new(master.as_raw_fd(), PollFlags::POLLIN)];
+        let wait = tick.saturating_sub(last_tick.elapsed());
+        if poll(&mut fds, wait.as_millis() as i32)? > 0
+            && fds[0].revents().is_some_and(|r| r.contains(PollFlags::POLLIN))
+        {
+            let n = master.read(&mut buf)?;
+            for &b in &buf[..n] {
+                match b {
+                    b'\r' => {
+                        let cmd = String::from_utf8_lossy(&line).to_string();
+                        line.clear();
+                        if cmd.trim().is_empty() {
+                            continue;
+                        }
+                        let response = sim.handle(&cmd);
+                        master.write_all(format!("{}\r", response).as_bytes())?;
+                    }
+                    b'\n' => {}
+                    _ => line.push(b),
+                }
+            }
+        }
+
+        if last_tick.elapsed() >= tick {
+            sim.tick();
+            last_tick = Instant::now();
+        }
+    }
+}
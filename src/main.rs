@@ -1,6 +1,7 @@
 mod cmd;
 mod scanner;
 mod server;
+mod simulator;
 
 
 use cmd::cli::Commands;
@@ -15,9 +16,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli = cmd::cli::Cli::parse();
     println!("debug level = {}", cli.debug);
 
+    // Only log when asked: stderr output would scribble over the console UI
+    if cli.debug > 0 {
+        let level = if cli.debug > 1 { tracing::Level::TRACE } else { tracing::Level::DEBUG };
+        tracing_subscriber::fmt()
+            .with_max_level(level)
+            .with_writer(std::io::stderr)
+            .init();
+    }
+
     match &cli.command {
         Commands::Serve(args) => cmd::serve::run(args).await?,
-        Commands::Console(args) => cmd::console::run(args)?,
+        Commands::Console(args) => cmd::console::run(args).await?,
+        Commands::Simulate(args) => cmd::simulate::run(args)?,
+        Commands::Backup(args) => cmd::backup::run(args)?,
+        Commands::Settings(args) => cmd::settings::run(args)?,
     }
     Ok(())
 }
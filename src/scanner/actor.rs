@@ -0,0 +1,168 @@
+use std::fmt;
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot, watch};
+use super::{ScannerClient, ScannerError};
+
+type Job = Box<dyn FnOnce(&mut ScannerClient) + Send>;
+
+const MIN_BACKOFF: Duration = Duration::from_millis(250);
+const MAX_BACKOFF: Duration = Duration::from_secs(5);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConnectionState {
+    Connected,
+    /// The link has dropped; `attempts` reconnects have failed so far.
+    Disconnected { attempts: u32 },
+}
+
+impl fmt::Display for ConnectionState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConnectionState::Connected => write!(f, "Connected"),
+            ConnectionState::Disconnected { attempts: 0 } => write!(f, "Disconnected"),
+            ConnectionState::Disconnected { attempts } => {
+                write!(f, "Disconnected (retry {})", attempts)
+            }
+        }
+    }
+}
+
+/// A cloneable handle to a `ScannerClient` owned by a single tokio task.
+///
+/// Requests are closures run against the client one at a time, in the order
+/// they were queued, so a status poller and programming operations share
+/// the serial link fairly. The blocking serial I/O itself runs on tokio's
+/// blocking pool, never on a runtime worker.
+///
+/// If the link drops, the actor reconnects with exponential backoff and
+/// publishes its [`ConnectionState`]. Requests queued while disconnected
+/// fail fast with [`ScannerError::Disconnected`].
+#[derive(Clone)]
+pub struct ScannerHandle {
+    tx: mpsc::UnboundedSender<Job>,
+    state: watch::Receiver<ConnectionState>,
+}
+
+/// Runs `f` against `client` on the blocking pool, handing the client back
+/// with the result. Returns `None` if `f` panicked and took the client with it.
+async fn run_blocking<R: Send + 'static>(
+    mut client: ScannerClient,
+    f: impl FnOnce(&mut ScannerClient) -> R + Send + 'static,
+) -> Option<(ScannerClient, R)> {
+    tokio::task::spawn_blocking(move || {
+        let result = f(&mut client);
+        (client, result)
+    })
+    .await
+    .ok()
+}
+
+impl ScannerHandle {
+    /// Moves `client` into a new actor task. Must be called within a tokio
+    /// runtime.
+    pub fn spawn(client: ScannerClient) -> Self {
+        let (tx, mut rx) = mpsc::unbounded_channel::<Job>();
+        let (state_tx, state) = watch::channel(ConnectionState::Connected);
+
+        tokio::spawn(async move {
+            let mut client = client;
+            let mut backoff = MIN_BACKOFF;
+            let mut attempts = 0;
+
+            loop {
+                let state = if client.is_connected() {
+                    ConnectionState::Connected
+                } else {
+                    ConnectionState::Disconnected { attempts }
+                };
+                state_tx.send_if_modified(|s| std::mem::replace(s, state) != state);
+
+                let job = if client.is_connected() {
+                    rx.recv().await
+                } else {
+                    tokio::select! {
+                        job = rx.recv() => job,
+                        _ = tokio::time::sleep(backoff) => {
+                            let Some((c, result)) = run_blocking(client, |c| c.reconnect()).await
+                            else {
+                                break;
+                            };
+                            client = c;
+
+                            match result {
+                                Ok(()) => {
+                                    tracing::info!("Scanner reconnected");
+                                    backoff = MIN_BACKOFF;
+                                    attempts = 0;
+                                }
+                                Err(e) => {
+                                    tracing::debug!("Reconnect failed: {}", e);
+                                    backoff = (backoff * 2).min(MAX_BACKOFF);
+                                    attempts += 1;
+                                }
+                            }
+                            continue;
+                        }
+                    }
+                };
+
+                let Some(job) = job else { break };
+                let was_connected = client.is_connected();
+                let Some((c, ())) = run_blocking(client, job).await else { break };
+                client = c;
+                if was_connected && !client.is_connected() {
+                    tracing::warn!("Scanner disconnected");
+                }
+            }
+        });
+
+        Self { tx, state }
+    }
+
+    /// Watches the connection to the scanner.
+    pub fn connection(&self) -> watch::Receiver<ConnectionState> {
+        self.state.clone()
+    }
+
+    /// Queues `job` without waiting for it to run.
+    ///
+    /// For callers outside async code, such as the console UI loop, which
+    /// collect results through their own channel.
+    pub fn execute(&self, job: impl FnOnce(&mut ScannerClient) + Send + 'static) {
+        let _ = self.tx.send(Box::new(job));
+    }
+
+    /// Queues `f` and waits for its result.
+    pub async fn call<T, F>(&self, f: F) -> Result<T, ScannerError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut ScannerClient) -> Result<T, ScannerError> + Send + 'static,
+    {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.execute(move |client| {
+            let _ = reply_tx.send(f(client));
+        });
+        // The actor only goes away if a job panicked
+        reply_rx.await.unwrap_or(Err(ScannerError::Disconnected))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::transport::MemoryTransport;
+    use crate::simulator::Simulator;
+
+    #[tokio::test]
+    async fn test_calls_run_in_order() {
+        let mut sim = Simulator::new();
+        let client = ScannerClient::new(Box::new(MemoryTransport::new(move |cmd| Some(sim.handle(cmd)))));
+        let scanner = ScannerHandle::spawn(client);
+
+        let set = scanner.call(|c| c.set_squelch(9));
+        let get = scanner.call(|c| c.get_squelch());
+        let (set, get) = tokio::join!(set, get);
+        set.unwrap();
+        assert_eq!(get.unwrap(), 9);
+    }
+}
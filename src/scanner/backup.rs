@@ -0,0 +1,195 @@
+use std::time::Duration;
+use chrono::{SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use super::{Frequency, ScannerClient, ScannerError};
+use super::channel::ChannelInfo;
+use super::close_call::CloseCallSettings;
+use super::search::{SEARCH_RANGE_COUNT, SearchRange, ServiceGroup};
+use super::settings::ScannerSettings;
+
+/// The backup file layout, bumped whenever it changes incompatibly.
+pub const BACKUP_VERSION: u32 = 1;
+
+/// How many times in a row a read is tried before giving up.
+const ATTEMPTS: usize = 5;
+
+/// How long to let a dropped link come back before reconnecting.
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// Everything programmable in the radio, as written to a backup file.
+/// Channels come last, so a diff of two backups reads top to bottom.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Backup {
+    pub version: u32,
+    pub model: String,
+    pub firmware: String,
+    /// When the backup was taken, RFC 3339 in UTC.
+    pub created: String,
+    pub settings: ScannerSettings,
+    /// Which banks are scanned, bank 1 first.
+    pub scan_banks: Vec<bool>,
+    pub close_call: CloseCallSettings,
+    pub search_ranges: Vec<SearchRange>,
+    /// Which search ranges a custom search covers, range 1 first.
+    pub custom_search: [bool; SEARCH_RANGE_COUNT],
+    /// The service searches that are switched on.
+    pub service_groups: Vec<ServiceGroup>,
+    pub lockouts: Vec<Frequency>,
+    /// The programmed channels; empty ones are left out.
+    pub channels: Vec<ChannelInfo>,
+}
+
+/// Failed attempts at a read since the last one that succeeded.
+#[derive(Default)]
+struct Retries {
+    failures: usize,
+}
+
+impl Retries {
+    fn succeeded(&mut self) {
+        self.failures = 0;
+    }
+
+    /// Gets `client` ready for another try after `e`, or hands `e` back if
+    /// it isn't worth one.
+    fn failed(
+        &mut self,
+        client: &mut ScannerClient,
+        what: &str,
+        e: ScannerError,
+    ) -> Result<(), ScannerError> {
+        self.failures += 1;
+        if !e.is_transient() || self.failures >= ATTEMPTS {
+            return Err(e);
+        }
+        tracing::warn!("Reading {} failed ({}), trying again", what, e);
+        if !client.is_connected() {
+            std::thread::sleep(RECONNECT_DELAY);
+            if let Err(e) = client.reconnect() {
+                tracing::debug!("Reconnect failed: {}", e);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ScannerClient {
+    /// Reads the whole radio, calling `progress(part, done, total)` as it
+    /// goes. Each part, and each channel, gets a few attempts, reconnecting
+    /// if the link drops; channels already read are kept, so a retry
+    /// carries on from the one that failed.
+    pub fn backup(
+        &mut self,
+        mut progress: impl FnMut(&str, usize, usize),
+    ) -> Result<Backup, ScannerError> {
+        let model = self.with_retries("model", |c| c.model().cloned())?;
+        progress("model", 1, 1);
+        let settings = self.with_retries("settings", |c| c.program()?.resume_scan().get_settings())?;
+        progress("settings", 1, 1);
+        let scan_banks =
+            self.with_retries("scan banks", |c| c.program()?.resume_scan().get_scan_banks())?;
+        progress("scan banks", 1, 1);
+        let close_call = self.with_retries("close call", |c| c.get_close_call())?;
+        progress("close call", 1, 1);
+        let (search_ranges, custom_search) = self.with_retries("search ranges", |c| {
+            let mut session = c.program()?.resume_scan();
+            Ok((session.get_search_ranges()?, session.get_search_groups()?))
+        })?;
+        progress("search ranges", 1, 1);
+        let service_groups = self.with_retries("service groups", |c| c.get_service_groups())?;
+        progress("service groups", 1, 1);
+        let lockouts = self.with_retries("lockouts", |c| {
+            c.program()?.resume_scan().get_lockouts(|_| {})
+        })?;
+        progress("lockouts", 1, 1);
+
+        let count = model.capabilities.channel_count as usize;
+        let mut channels = Vec::with_capacity(count);
+        let mut retries = Retries::default();
+        while channels.len() < count {
+            let read = self.program().and_then(|session| {
+                let mut session = session.resume_scan();
+                while channels.len() < count {
+                    channels.push(session.get_channel(channels.len() as u32 + 1)?);
+                    retries.succeeded();
+                    progress("channels", channels.len(), count);
+                }
+                Ok(())
+            });
+            if let Err(e) = read {
+                retries.failed(self, "channels", e)?;
+            }
+        }
+        channels.retain(|channel| !channel.is_empty());
+
+        Ok(Backup {
+            version: BACKUP_VERSION,
+            model: model.name,
+            firmware: model.firmware,
+            created: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
+            settings,
+            scan_banks,
+            close_call,
+            search_ranges,
+            custom_search,
+            service_groups: service_groups.iter().filter(|&(_, on)| on).map(|(g, _)| g).collect(),
+            lockouts,
+            channels,
+        })
+    }
+
+    /// Runs `read` until it succeeds, fails for good or runs out of
+    /// [`ATTEMPTS`].
+    fn with_retries<T>(
+        &mut self,
+        what: &str,
+        mut read: impl FnMut(&mut Self) -> Result<T, ScannerError>,
+    ) -> Result<T, ScannerError> {
+        let mut retries = Retries::default();
+        loop {
+            match read(self) {
+                Ok(value) => return Ok(value),
+                Err(e) => retries.failed(self, what, e)?,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::transport::MemoryTransport;
+    use crate::simulator::Simulator;
+
+    #[test]
+    fn test_backup_resumes_after_timeouts() {
+        let mut sim = Simulator::new();
+        let mut reads = 0;
+        let mut client = ScannerClient::new(Box::new(MemoryTransport::new(move |cmd| {
+            // Drop every 50th channel read, as a flaky cable might; more
+            // failures in all than any one channel is allowed
+            if cmd.starts_with("CIN,") {
+                reads += 1;
+                if reads % 50 == 0 {
+                    return None;
+                }
+            }
+            Some(sim.handle(cmd))
+        })));
+        client.set_timeout("CIN", Duration::from_millis(10));
+
+        let mut last = (String::new(), 0, 0);
+        let backup = client
+            .backup(|what, done, total| last = (what.to_string(), done, total))
+            .unwrap();
+        assert_eq!(last, ("channels".to_string(), 500, 500));
+        assert_eq!(backup.model, "BC125AT");
+        assert_eq!(backup.channels.len(), 9);
+        assert_eq!(backup.channels[1].name.as_str(), "BHX TOWER");
+        assert_eq!(backup.search_ranges.len(), SEARCH_RANGE_COUNT);
+
+        let json = serde_json::to_string_pretty(&backup).unwrap();
+        assert!(json.contains(r#""frequency": "118.3000""#));
+        assert_eq!(serde_json::from_str::<Backup>(&json).unwrap(), backup);
+    }
+}
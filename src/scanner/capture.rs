@@ -0,0 +1,197 @@
+use std::collections::{HashMap, VecDeque};
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+use std::time::Duration;
+use chrono::{SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use super::ScannerError;
+use super::transport::ScannerTransport;
+
+/// One command and what came back, as a line of a capture file.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Exchange {
+    /// When the command was sent, RFC 3339 in UTC.
+    pub timestamp: String,
+    pub command: String,
+    /// The reply line, `None` if none arrived (timeout or disconnect).
+    pub response: Option<String>,
+    /// Unrelated lines read and discarded before the reply.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stray: Vec<String>,
+    pub latency_ms: u64,
+}
+
+/// Appends every exchange to a JSONL capture file.
+pub struct Recorder {
+    file: File,
+}
+
+impl Recorder {
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self { file })
+    }
+
+    pub fn record(
+        &mut self,
+        command: &str,
+        result: &Result<String, ScannerError>,
+        stray: Vec<String>,
+        latency: Duration,
+    ) -> io::Result<()> {
+        // ERR and NG are lines the scanner sent, so keep them for replay
+        let response = match result {
+            Ok(line) => Some(line.clone()),
+            Err(ScannerError::FormatError(_)) => Some("ERR".to_string()),
+            Err(ScannerError::NotNow(_)) => Some("NG".to_string()),
+            Err(ScannerError::UnexpectedResponse { got, .. }) => Some(got.clone()),
+            Err(_) => None,
+        };
+        let exchange = Exchange {
+            timestamp: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+            command: command.to_string(),
+            response,
+            stray,
+            latency_ms: latency.as_millis() as u64,
+        };
+        let line = serde_json::to_string(&exchange).map_err(io::Error::other)?;
+        writeln!(self.file, "{}", line)
+    }
+}
+
+/// Plays a capture file back as if it were the scanner.
+///
+/// Each command is answered by the next unused exchange recorded for that
+/// same command, whatever other commands were sent in between, so a poll
+/// sent more or fewer times than in the recording doesn't disturb the rest.
+/// Replies are served immediately, so a replay runs the same way every time.
+/// Commands with no recording left, or recorded without a reply, time out.
+pub struct ReplayTransport {
+    // Recorded exchanges by command, oldest first
+    exchanges: HashMap<String, VecDeque<Exchange>>,
+    pending: VecDeque<String>,
+}
+
+impl ReplayTransport {
+    pub fn new(exchanges: impl IntoIterator<Item = Exchange>) -> Self {
+        let mut by_command: HashMap<String, VecDeque<Exchange>> = HashMap::new();
+        for exchange in exchanges {
+            by_command.entry(exchange.command.clone()).or_default().push_back(exchange);
+        }
+        Self {
+            exchanges: by_command,
+            pending: VecDeque::new(),
+        }
+    }
+
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let reader = BufReader::new(File::open(path)?);
+        let mut exchanges = Vec::new();
+        for (n, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let exchange = serde_json::from_str(&line).map_err(|e| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("line {}: {}", n + 1, e))
+            })?;
+            exchanges.push(exchange);
+        }
+        Ok(Self::new(exchanges))
+    }
+}
+
+impl ScannerTransport for ReplayTransport {
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        let Some(exchange) = self.exchanges.get_mut(line).and_then(VecDeque::pop_front) else {
+            tracing::warn!("No recorded reply left for {}", line);
+            return Ok(());
+        };
+        self.pending.extend(exchange.stray);
+        self.pending.extend(exchange.response);
+        Ok(())
+    }
+
+    fn read_line(&mut self, _timeout: Duration) -> io::Result<String> {
+        self.pending
+            .pop_front()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::TimedOut, "No recorded reply"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::ScannerClient;
+    use crate::scanner::transport::MemoryTransport;
+    use crate::simulator::Simulator;
+
+    #[test]
+    fn test_record_and_replay() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("capture.jsonl");
+
+        let mut sim = Simulator::new();
+        let mut transport = MemoryTransport::new(move |cmd| Some(sim.handle(cmd)));
+        transport.push_line("GLG,,,,,,,,,,,,");
+        let mut client = ScannerClient::new(Box::new(transport));
+        client.record_to(&path).unwrap();
+        client.send_command("MDL").unwrap();
+        client.set_squelch(5).unwrap();
+        client.get_squelch().unwrap();
+        assert!(client.send_command("BOGUS").is_err());
+
+        let recorded: Vec<Exchange> = std::fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(recorded.len(), 4);
+        assert_eq!(recorded[0].stray, vec!["GLG,,,,,,,,,,,,"]);
+        assert_eq!(recorded[3].response.as_deref(), Some("ERR"));
+
+        // The capture drives a client the same way, stray line included
+        let mut replay = ScannerClient::new(Box::new(ReplayTransport::open(&path).unwrap()));
+        assert_eq!(replay.send_command("MDL").unwrap(), "MDL,BC125AT");
+        assert_eq!(replay.get_squelch().unwrap(), 5);
+        assert!(matches!(replay.send_command("BOGUS"), Err(ScannerError::FormatError(_))));
+        assert!(matches!(replay.send_command("VER"), Err(ScannerError::Timeout(_))));
+    }
+
+    #[test]
+    fn test_replay_with_more_or_fewer_polls() {
+        let exchange = |command: &str, response: &str| Exchange {
+            timestamp: String::new(),
+            command: command.to_string(),
+            response: Some(response.to_string()),
+            stray: vec![],
+            latency_ms: 0,
+        };
+        let glg = "GLG,01239750,AM,,0,,,BHX RADAR,1,0,,52,";
+        let replay = ReplayTransport::new([
+            exchange("GLG", glg),
+            exchange("GLG", glg),
+            exchange("PRG", "PRG,OK"),
+            exchange("CIN,1", "CIN,1,BHX APPR,01189100,AM,0,2,0,0"),
+            exchange("GLG", glg),
+            exchange("CIN,2", "CIN,2,BHX TOWER,01183000,AM,0,2,0,0"),
+            exchange("EPG", "EPG,OK"),
+            exchange("GLG", glg),
+        ]);
+        let mut client = ScannerClient::new(Box::new(replay));
+
+        // Polls at other times than in the recording: three before the
+        // channels where two were recorded, none in between, one after
+        for _ in 0..3 {
+            assert_eq!(client.send_command("GLG").unwrap(), glg);
+        }
+        let mut session = client.program().unwrap();
+        assert_eq!(session.get_channel(1).unwrap().name.as_str(), "BHX APPR");
+        assert_eq!(session.get_channel(2).unwrap().name.as_str(), "BHX TOWER");
+        drop(session);
+        assert_eq!(client.send_command("GLG").unwrap(), glg);
+        // Every recorded GLG has been used
+        assert!(matches!(client.send_command("GLG"), Err(ScannerError::Timeout(_))));
+    }
+}
@@ -0,0 +1,382 @@
+use std::fmt;
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+use super::{Frequency, ScannerError};
+use super::tone::Tone;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum Modulation {
+    #[default]
+    Auto,
+    Am,
+    Fm,
+    Nfm,
+}
+
+impl Modulation {
+    pub const ALL: [Modulation; 4] = [Modulation::Auto, Modulation::Am, Modulation::Fm, Modulation::Nfm];
+}
+
+impl fmt::Display for Modulation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Modulation::Auto => "AUTO",
+            Modulation::Am => "AM",
+            Modulation::Fm => "FM",
+            Modulation::Nfm => "NFM",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for Modulation {
+    type Err = ScannerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Modulation::ALL
+            .into_iter()
+            .find(|m| m.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(|| ScannerError::InvalidArgument(format!("Unknown modulation {:?}", s)))
+    }
+}
+
+impl From<Modulation> for String {
+    fn from(modulation: Modulation) -> Self {
+        modulation.to_string()
+    }
+}
+
+impl TryFrom<String> for Modulation {
+    type Error = ScannerError;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        name.parse()
+    }
+}
+
+/// How long the scanner waits on a channel after a transmission ends, in
+/// seconds. Negative values are the radio's "resume after N seconds
+/// regardless" setting.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "i8", try_from = "i8")]
+pub struct Delay(i8);
+
+impl Delay {
+    pub const VALUES: [i8; 8] = [-10, -5, 0, 1, 2, 3, 4, 5];
+
+    pub fn new(seconds: i8) -> Result<Self, ScannerError> {
+        if !Self::VALUES.contains(&seconds) {
+            return Err(ScannerError::InvalidArgument(format!(
+                "Delay must be one of {:?}, got {}",
+                Self::VALUES,
+                seconds
+            )));
+        }
+        Ok(Self(seconds))
+    }
+
+    pub fn seconds(self) -> i8 {
+        self.0
+    }
+}
+
+impl From<Delay> for i8 {
+    fn from(delay: Delay) -> Self {
+        delay.0
+    }
+}
+
+impl TryFrom<i8> for Delay {
+    type Error = ScannerError;
+
+    fn try_from(seconds: i8) -> Result<Self, Self::Error> {
+        Self::new(seconds)
+    }
+}
+
+impl Default for Delay {
+    /// The radio's factory setting.
+    fn default() -> Self {
+        Self(2)
+    }
+}
+
+/// The most characters the radio keeps in a channel name.
+pub const NAME_LEN: usize = 16;
+
+/// A channel name the radio can store: at most [`NAME_LEN`] printable ASCII
+/// characters. Commas are refused, as `CIN` has no way to escape them.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ChannelName(String);
+
+impl ChannelName {
+    pub fn new(name: &str) -> Result<Self, ScannerError> {
+        let invalid =
+            |why: String| ScannerError::InvalidArgument(format!("Channel name {:?} {}", name, why));
+        if let Some(c) = name.chars().find(|&c| !is_storable(c)) {
+            return Err(invalid(format!("contains {:?}, which the scanner can't store", c)));
+        }
+        if name.len() > NAME_LEN {
+            return Err(invalid(format!("is longer than {} characters", NAME_LEN)));
+        }
+        Ok(Self(name.to_string()))
+    }
+
+    /// [`ChannelName::new`] after replacing accented letters and typographic
+    /// punctuation with plain ASCII, so `Zürich Tower` becomes `Zurich Tower`.
+    pub fn transliterate(name: &str) -> Result<Self, ScannerError> {
+        Self::new(&to_ascii(name))
+    }
+
+    /// A name read back from the radio. Whatever [`ChannelName::new`] would
+    /// refuse, such as a comma entered on the keypad, is transliterated or
+    /// blanked and the name cut to length, so it can always be written back.
+    fn from_scanner(name: &str) -> Self {
+        let plain: String = to_ascii(name)
+            .chars()
+            .map(|c| if is_storable(c) { c } else { ' ' })
+            .take(NAME_LEN)
+            .collect();
+        if plain != name {
+            tracing::warn!("Channel name {:?} read back as {:?}", name, plain);
+        }
+        Self(plain)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Whether the scanner can store `c` in a name: printable ASCII other than
+/// the comma separating `CIN` fields.
+fn is_storable(c: char) -> bool {
+    c != ',' && (' '..='~').contains(&c)
+}
+
+/// `name` with accented letters and typographic punctuation spelt in ASCII.
+fn to_ascii(name: &str) -> String {
+    let mut plain = String::with_capacity(name.len());
+    for c in name.chars() {
+        let lower = c.to_lowercase().next().unwrap_or(c);
+        match ascii_for(lower) {
+            Some(s) if c != lower => plain.push_str(&s.to_uppercase()),
+            Some(s) => plain.push_str(s),
+            None => plain.push(c),
+        }
+    }
+    plain
+}
+
+/// The ASCII spelling of a lowercase letter or punctuation mark that the
+/// scanner can't display.
+fn ascii_for(c: char) -> Option<&'static str> {
+    Some(match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ą' => "a",
+        'æ' => "ae",
+        'ç' | 'ć' | 'č' => "c",
+        'ď' | 'đ' => "d",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ę' | 'ě' => "e",
+        'ì' | 'í' | 'î' | 'ï' => "i",
+        'ł' => "l",
+        'ñ' | 'ń' | 'ň' => "n",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ő' => "o",
+        'œ' => "oe",
+        'ř' => "r",
+        'ś' | 'š' => "s",
+        'ß' => "ss",
+        'ť' => "t",
+        'ù' | 'ú' | 'û' | 'ü' | 'ů' | 'ű' => "u",
+        'ý' | 'ÿ' => "y",
+        'ź' | 'ż' | 'ž' => "z",
+        '‘' | '’' => "'",
+        '“' | '”' => "\"",
+        '–' | '—' => "-",
+        _ => return None,
+    })
+}
+
+impl fmt::Display for ChannelName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for ChannelName {
+    type Err = ScannerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<String> for ChannelName {
+    type Error = ScannerError;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        Self::new(&name)
+    }
+}
+
+impl From<ChannelName> for String {
+    fn from(name: ChannelName) -> Self {
+        name.0
+    }
+}
+
+/// One memory channel, as read and written with `CIN`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChannelInfo {
+    pub index: u32,
+    pub name: ChannelName,
+    /// Zero for an empty channel.
+    pub frequency: Frequency,
+    pub modulation: Modulation,
+    pub tone: Tone,
+    pub delay: Delay,
+    pub lockout: bool,
+    pub priority: bool,
+}
+
+impl ChannelInfo {
+    /// An empty channel, as the radio reports it after `DCH`.
+    pub fn empty(index: u32) -> Self {
+        Self {
+            index,
+            name: ChannelName::default(),
+            frequency: Frequency::default(),
+            modulation: Modulation::default(),
+            tone: Tone::default(),
+            delay: Delay::default(),
+            lockout: false,
+            priority: false,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frequency.is_zero()
+    }
+
+    /// Parses a `CIN,[INDEX],[NAME],[FRQ],[MOD],[CTCSS/DCS],[DLY],[LOUT],[PRI]`
+    /// reply.
+    pub fn parse(line: &str) -> Result<Self, ScannerError> {
+        let bad = |what: &str| ScannerError::UnexpectedResponse {
+            sent: "CIN".to_string(),
+            got: format!("{} ({})", line, what),
+        };
+        let flag = |s: &str, what: &str| match s {
+            "0" => Ok(false),
+            "1" => Ok(true),
+            _ => Err(bad(what)),
+        };
+
+        let parts: Vec<&str> = line.split(',').collect();
+        if parts.len() < 9 || parts[0] != "CIN" {
+            return Err(bad("expected 9 fields"));
+        }
+        // Take fixed fields from both ends, so a name with a comma in it
+        // still parses
+        let tail = &parts[parts.len() - 6..];
+        let name = ChannelName::from_scanner(&parts[2..parts.len() - 6].join(","));
+
+        Ok(Self {
+            index: parts[1].parse().map_err(|_| bad("index"))?,
+            name,
+            frequency: Frequency::from_scanner(tail[0]).map_err(|_| bad("frequency"))?,
+            modulation: tail[1].parse().map_err(|_| bad("modulation"))?,
+            tone: tail[2]
+                .parse()
+                .ok()
+                .and_then(|code| Tone::from_code(code).ok())
+                .ok_or_else(|| bad("tone"))?,
+            delay: tail[3]
+                .parse()
+                .ok()
+                .and_then(|d| Delay::new(d).ok())
+                .ok_or_else(|| bad("delay"))?,
+            lockout: flag(tail[4], "lockout")?,
+            priority: flag(tail[5], "priority")?,
+        })
+    }
+
+    /// The `CIN` command that writes this channel. Fails if the tone isn't
+    /// one the scanner supports.
+    pub fn to_command(&self) -> Result<String, ScannerError> {
+        Ok(format!(
+            "CIN,{},{},{},{},{},{},{},{}",
+            self.index,
+            self.name,
+            self.frequency.to_scanner(),
+            self.modulation,
+            self.tone.code()?,
+            self.delay.seconds(),
+            self.lockout as u8,
+            self.priority as u8
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cin_round_trip() {
+        for line in [
+            "CIN,52,MARINE 6,01563000,FM,0,2,0,0",
+            "CIN,7,REPEATER,01456250,NFM,88,-10,1,1",
+            "CIN,500,,00000000,AUTO,0,2,0,0",
+        ] {
+            assert_eq!(ChannelInfo::parse(line).unwrap().to_command().unwrap(), line);
+        }
+
+        let chan = ChannelInfo::parse("CIN,7,REPEATER,01456250,NFM,88,-5,1,0").unwrap();
+        assert_eq!(chan.frequency.to_string(), "145.6250");
+        assert_eq!(chan.modulation, Modulation::Nfm);
+        assert_eq!(chan.tone, Tone::Ctcss(1514));
+        assert_eq!(chan.delay.seconds(), -5);
+        assert!(chan.lockout && !chan.priority);
+        assert!(ChannelInfo::parse("CIN,500,,00000000,AUTO,0,2,0,0").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_cin_parse_errors() {
+        assert!(ChannelInfo::parse("CIN,1,A,01563000,FM,0,2,0").is_err());
+        assert!(ChannelInfo::parse("CIN,1,A,01563000,USB,0,2,0,0").is_err());
+        assert!(ChannelInfo::parse("CIN,1,A,01563000,FM,0,7,0,0").is_err());
+        assert!(ChannelInfo::parse("CIN,1,A,01563000,FM,0,2,2,0").is_err());
+        assert!(ChannelInfo::parse("CIN,1,A,01563000,FM,114,2,0,0").is_err());
+        assert!(Delay::new(-7).is_err());
+    }
+
+    #[test]
+    fn test_channel_names() {
+        assert_eq!(ChannelName::new("BHX TOWER").unwrap().as_str(), "BHX TOWER");
+        assert!(ChannelName::new("16 CHARS EXACTLY").is_ok());
+        assert!(ChannelName::new("SEVENTEEN CHARS!!").is_err());
+        assert!(ChannelName::new("TOWER, WEST").is_err());
+        assert!(ChannelName::new("Zürich").is_err());
+        assert!(ChannelName::new("TAB\tHERE").is_err());
+
+        let name = ChannelName::transliterate("Zürich Tower").unwrap();
+        assert_eq!(name.to_string(), "Zurich Tower");
+        assert_eq!(ChannelName::transliterate("ÆRØ Ålborg").unwrap().as_str(), "AERO Alborg");
+        assert_eq!(ChannelName::transliterate("Straße – 1").unwrap().as_str(), "Strasse - 1");
+        assert!(ChannelName::transliterate("Tower, West").is_err());
+        assert!(ChannelName::transliterate("東京").is_err());
+
+        let json = serde_json::to_string(&name).unwrap();
+        assert_eq!(json, "\"Zurich Tower\"");
+        assert_eq!(serde_json::from_str::<ChannelName>(&json).unwrap(), name);
+        assert!(serde_json::from_str::<ChannelName>("\"A,B\"").is_err());
+
+        // Names read from the radio are made storable, so they write back
+        let chan = ChannelInfo::parse("CIN,3,TOWER, WEST,01189100,AM,0,2,0,0").unwrap();
+        assert_eq!(chan.name.as_str(), "TOWER  WEST");
+        assert_eq!(chan.to_command().unwrap(), "CIN,3,TOWER  WEST,01189100,AM,0,2,0,0");
+        let chan = ChannelInfo::parse("CIN,3,Zürich Tower Approach,01189100,AM,0,2,0,0").unwrap();
+        assert_eq!(chan.name.as_str(), "Zurich Tower App");
+    }
+}
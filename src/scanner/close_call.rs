@@ -0,0 +1,256 @@
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use super::{Frequency, ProgramSession, ScannerClient, ScannerError};
+use super::channel::Modulation;
+use super::display::DisplayStatus;
+use super::status::ScanStatus;
+
+/// The bands Close Call listens on, in `CC_BAND` order.
+pub const CLOSE_CALL_BANDS: [&str; 5] = [
+    "25-54 MHz",
+    "108-137 MHz",
+    "137-174 MHz",
+    "225-380 MHz",
+    "400-512 MHz",
+];
+
+/// How Close Call runs alongside scanning (`CC_MODE`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CloseCallMode {
+    Off,
+    /// Checks for nearby transmissions every couple of seconds, interrupting
+    /// whatever is being received.
+    Priority,
+    /// Only checks between transmissions.
+    DoNotDisturb,
+}
+
+impl CloseCallMode {
+    pub const ALL: [CloseCallMode; 3] = [
+        CloseCallMode::Off,
+        CloseCallMode::Priority,
+        CloseCallMode::DoNotDisturb,
+    ];
+}
+
+impl fmt::Display for CloseCallMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            CloseCallMode::Off => "Off",
+            CloseCallMode::Priority => "Priority",
+            CloseCallMode::DoNotDisturb => "DND",
+        })
+    }
+}
+
+/// The Close Call settings, as read and written with `CLC`:
+/// `CLC,[CC_MODE],[ALTB],[ALTL],[CC_BAND],[LOUT]`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CloseCallSettings {
+    pub mode: CloseCallMode,
+    /// Beep on a hit.
+    pub alert_beep: bool,
+    /// Flash the backlight on a hit.
+    pub alert_light: bool,
+    /// Which of [`CLOSE_CALL_BANDS`] are checked.
+    pub bands: [bool; CLOSE_CALL_BANDS.len()],
+    /// The `LOUT` flag.
+    pub lockout: bool,
+}
+
+impl CloseCallSettings {
+    pub fn parse(line: &str) -> Result<Self, ScannerError> {
+        let bad = |what: &str| ScannerError::UnexpectedResponse {
+            sent: "CLC".to_string(),
+            got: format!("{} ({})", line, what),
+        };
+        let flag = |s: &str, what: &str| match s {
+            "0" => Ok(false),
+            "1" => Ok(true),
+            _ => Err(bad(what)),
+        };
+
+        let parts: Vec<&str> = line.split(',').collect();
+        let ["CLC", mode, beep, light, bands, lockout] = parts.as_slice() else {
+            return Err(bad("expected 6 fields"));
+        };
+        let mode = mode
+            .parse::<usize>()
+            .ok()
+            .and_then(|m| CloseCallMode::ALL.get(m).copied())
+            .ok_or_else(|| bad("mode"))?;
+        if bands.len() != CLOSE_CALL_BANDS.len() {
+            return Err(bad("bands"));
+        }
+        let mut enabled = [false; CLOSE_CALL_BANDS.len()];
+        for (on, c) in enabled.iter_mut().zip(bands.chars()) {
+            *on = flag(&c.to_string(), "bands")?;
+        }
+        Ok(Self {
+            mode,
+            alert_beep: flag(beep, "alert beep")?,
+            alert_light: flag(light, "alert light")?,
+            bands: enabled,
+            lockout: flag(lockout, "lockout")?,
+        })
+    }
+
+    pub fn to_command(&self) -> String {
+        let bands: String = self.bands.iter().map(|&on| if on { '1' } else { '0' }).collect();
+        format!(
+            "CLC,{},{},{},{},{}",
+            self.mode as u8,
+            self.alert_beep as u8,
+            self.alert_light as u8,
+            bands,
+            self.lockout as u8
+        )
+    }
+}
+
+impl ProgramSession<'_> {
+    pub fn get_close_call(&mut self) -> Result<CloseCallSettings, ScannerError> {
+        let response = self.client.command("CLC")?;
+        CloseCallSettings::parse(&response)
+    }
+
+    pub fn set_close_call(&mut self, settings: &CloseCallSettings) -> Result<(), ScannerError> {
+        self.client.send_set(&settings.to_command())
+    }
+}
+
+impl ScannerClient {
+    /// Reads the Close Call settings in a program mode session of its own,
+    /// then resumes scanning.
+    pub fn get_close_call(&mut self) -> Result<CloseCallSettings, ScannerError> {
+        self.program()?.resume_scan().get_close_call()
+    }
+
+    /// Writes the Close Call settings in a program mode session of its own,
+    /// then resumes scanning.
+    pub fn set_close_call(&mut self, settings: &CloseCallSettings) -> Result<(), ScannerError> {
+        self.program()?.resume_scan().set_close_call(settings)
+    }
+}
+
+/// A transmission Close Call found nearby.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CloseCallHit {
+    pub frequency: Frequency,
+    pub modulation: Modulation,
+}
+
+impl CloseCallHit {
+    /// A hit is a reception with no memory channel while the radio shows
+    /// Close Call. Search, tuned and weather receptions have no channel in
+    /// `GLG` either, so the display decides.
+    pub fn detect(status: Option<&ScanStatus>, display: &DisplayStatus) -> Option<Self> {
+        let status = status.filter(|s| s.squelch_open && s.channel.is_none())?;
+        if !display.shows_close_call() {
+            return None;
+        }
+        Some(Self {
+            frequency: status.frequency,
+            modulation: status.modulation,
+        })
+    }
+}
+
+/// Turns polled statuses into hits, reporting each hit once rather than on
+/// every poll while it lasts.
+#[derive(Default)]
+pub struct CloseCallDetector {
+    current: Option<Frequency>,
+}
+
+impl CloseCallDetector {
+    /// The hit that started since the last update, if any.
+    pub fn update(
+        &mut self,
+        status: Option<&ScanStatus>,
+        display: &DisplayStatus,
+    ) -> Option<CloseCallHit> {
+        let hit = CloseCallHit::detect(status, display);
+        let previous = std::mem::replace(&mut self.current, hit.as_ref().map(|h| h.frequency));
+        hit.filter(|h| previous != Some(h.frequency))
+    }
+
+    /// Whether a hit is being received now.
+    pub fn is_active(&self) -> bool {
+        self.current.is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::transport::MemoryTransport;
+    use crate::simulator::Simulator;
+
+    #[test]
+    fn test_clc_round_trip() {
+        let line = "CLC,2,1,0,01101,0";
+        let settings = CloseCallSettings::parse(line).unwrap();
+        assert_eq!(settings.mode, CloseCallMode::DoNotDisturb);
+        assert!(settings.alert_beep && !settings.alert_light && !settings.lockout);
+        assert_eq!(settings.bands, [false, true, true, false, true]);
+        assert_eq!(settings.to_command(), line);
+
+        assert!(CloseCallSettings::parse("CLC,3,1,0,01101,0").is_err());
+        assert!(CloseCallSettings::parse("CLC,1,1,0,0110,0").is_err());
+        assert!(CloseCallSettings::parse("CLC,1,1,0,01102,0").is_err());
+        assert!(CloseCallSettings::parse("CLC,1,1,0,01101").is_err());
+
+        let mut sim = Simulator::new();
+        let mut client = ScannerClient::new(Box::new(MemoryTransport::new(move |cmd| {
+            Some(sim.handle(cmd))
+        })));
+        client.set_close_call(&settings).unwrap();
+        assert_eq!(client.get_close_call().unwrap(), settings);
+    }
+
+    #[test]
+    fn test_hit_detection() {
+        let status = |line: &str| ScanStatus::parse(line).unwrap();
+        let display = |text: &str| {
+            DisplayStatus::parse(&format!("STS,1,{:<16},{:16},1,0,0,,,4", text, "")).unwrap()
+        };
+        let hit = status("GLG,01625500,FM,,0,,,,1,0,,,");
+        let channel = status("GLG,01239750,AM,,0,,,BHX RADAR,1,0,,3,");
+        let closed = status("GLG,01625500,FM,,0,,,,0,0,,,");
+
+        let mut detector = CloseCallDetector::default();
+        let found = detector.update(hit.as_ref(), &display("Close Call")).unwrap();
+        assert_eq!(found.frequency.to_string(), "162.5500");
+        assert!(detector.is_active());
+        // Still the same hit
+        assert_eq!(detector.update(hit.as_ref(), &display("Close Call")), None);
+        assert_eq!(detector.update(closed.as_ref(), &display("Close Call")), None);
+        assert!(!detector.is_active());
+        assert!(detector.update(hit.as_ref(), &display("Close Call")).is_some());
+
+        assert_eq!(CloseCallHit::detect(channel.as_ref(), &display("BHX RADAR")), None);
+        assert_eq!(CloseCallHit::detect(hit.as_ref(), &display("Custom Search")), None);
+        assert_eq!(CloseCallHit::detect(None, &display("SCAN")), None);
+        assert!(CloseCallHit::detect(hit.as_ref(), &display("CC  162.5500")).is_some());
+    }
+
+    #[test]
+    fn test_tuned_and_weather_are_not_hits() {
+        let status = |line: &str| ScanStatus::parse(line).unwrap();
+        let display = |text: &str| {
+            DisplayStatus::parse(&format!("STS,1,{:<16},{:16},1,0,0,,,4", text, "")).unwrap()
+        };
+        // Holding on a frequency typed in, which GLG reports without a channel
+        let tuned = status("GLG,01455000,FM,,0,,,,1,0,,,");
+        assert_eq!(CloseCallHit::detect(tuned.as_ref(), &display("145.5000 FM")), None);
+
+        let weather = status("GLG,01625500,FM,,0,,,,1,0,,,");
+        assert_eq!(CloseCallHit::detect(weather.as_ref(), &display("WX  162.5500")), None);
+
+        let mut detector = CloseCallDetector::default();
+        assert_eq!(detector.update(weather.as_ref(), &display("WX  162.5500")), None);
+        assert!(!detector.is_active());
+    }
+}
@@ -0,0 +1,108 @@
+use std::io;
+use std::time::Duration;
+use serialport::{SerialPortInfo, SerialPortType};
+use super::model;
+use super::transport::{ScannerTransport, SerialTransport};
+
+/// Uniden's USB vendor ID.
+const UNIDEN_VID: u16 = 0x1965;
+/// Product IDs of Uniden handhelds using the BC125AT serial protocol.
+const SCANNER_PIDS: &[u16] = &[
+    0x0017, // BC125AT / UBC125XLT
+    0x001a, // UBC126AT
+];
+
+fn is_scanner_port(port: &SerialPortInfo) -> bool {
+    matches!(
+        &port.port_type,
+        SerialPortType::UsbPort(usb) if usb.vid == UNIDEN_VID && SCANNER_PIDS.contains(&usb.pid)
+    )
+}
+
+/// Asks the device at `path` for its model, returning it if it is a scanner
+/// we can drive.
+fn probe(path: &str) -> io::Result<Option<String>> {
+    let mut transport = SerialTransport::open(path)?;
+    transport.write_line("MDL")?;
+    let response = transport.read_line(Duration::from_millis(500))?;
+    Ok(response
+        .strip_prefix("MDL,")
+        .filter(|name| model::is_known(name))
+        .map(str::to_string))
+}
+
+/// Picks the single confirmed scanner out of the probed ports.
+fn choose(probed: Vec<(String, io::Result<Option<String>>)>) -> io::Result<String> {
+    let describe = |probed: &[(String, io::Result<Option<String>>)]| {
+        probed
+            .iter()
+            .map(|(path, result)| match result {
+                Ok(Some(model)) => format!("{} ({})", path, model),
+                Ok(None) => format!("{} (not a scanner)", path),
+                Err(e) => format!("{} ({})", path, e),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let confirmed: Vec<&String> = probed
+        .iter()
+        .filter(|(_, result)| matches!(result, Ok(Some(_))))
+        .map(|(path, _)| path)
+        .collect();
+
+    match confirmed.as_slice() {
+        [path] => Ok(path.to_string()),
+        [] if probed.is_empty() => Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "No Uniden scanner found on USB",
+        )),
+        [] => Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("No Uniden scanner answered MDL. Candidates: {}", describe(&probed)),
+        )),
+        _ => Err(io::Error::other(format!(
+            "Several scanners found, pick one with --device: {}",
+            describe(&probed)
+        ))),
+    }
+}
+
+/// Finds the scanner among the USB serial ports by Uniden's VID/PID, then
+/// confirms it by model with `MDL`.
+pub fn find_scanner() -> io::Result<String> {
+    let ports = serialport::available_ports()?;
+    let probed = ports
+        .iter()
+        .filter(|port| is_scanner_port(port))
+        .map(|port| (port.port_name.clone(), probe(&port.port_name)))
+        .collect();
+    choose(probed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_choose_single_confirmed_port() {
+        let probed = vec![
+            ("/dev/ttyACM0".to_string(), Ok(None)),
+            ("/dev/ttyACM1".to_string(), Ok(Some("BC125AT".to_string()))),
+        ];
+        assert_eq!(choose(probed).unwrap(), "/dev/ttyACM1");
+    }
+
+    #[test]
+    fn test_choose_lists_candidates_when_ambiguous() {
+        let probed = vec![
+            ("/dev/ttyACM0".to_string(), Ok(Some("BC125AT".to_string()))),
+            ("/dev/ttyACM1".to_string(), Ok(Some("UBC125XLT".to_string()))),
+        ];
+        let err = choose(probed).unwrap_err().to_string();
+        assert!(err.contains("/dev/ttyACM0 (BC125AT)"));
+        assert!(err.contains("/dev/ttyACM1 (UBC125XLT)"));
+
+        assert_eq!(choose(vec![]).unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::VecDeque;
+use super::ScannerError;
+
+/// Characters per LCD line. Both the text and the mode of a line are sent
+/// padded to this width.
+pub const LINE_WIDTH: usize = 16;
+
+/// How one character cell of the LCD is drawn, from an `Ln_MODE` field.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CharStyle {
+    #[default]
+    Normal,
+    /// Light on dark, used for the cursor and selected menu items.
+    Reverse,
+    Underline,
+}
+
+impl CharStyle {
+    fn from_mode(c: char) -> Self {
+        match c {
+            '*' => CharStyle::Reverse,
+            '_' => CharStyle::Underline,
+            _ => CharStyle::Normal,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisplayLine {
+    pub text: String,
+    /// One style per character of `text`.
+    pub styles: Vec<CharStyle>,
+    /// Drawn in the large font.
+    pub large: bool,
+}
+
+/// What the scanner's LCD shows, from an `STS` reply:
+/// `STS,[DSP_FORM],[L1_CHAR],[L1_MODE],...,[Ln_CHAR],[Ln_MODE],[SQL],[MUT],[BAT],[RSV],[RSV],[SIG_LVL],...`.
+///
+/// `DSP_FORM` has one digit per line, `1` for the large font. The radio's
+/// icons are characters outside ASCII in the line text, which arrive as the
+/// Latin-1 characters with the same byte value.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DisplayStatus {
+    pub lines: Vec<DisplayLine>,
+    pub squelch_open: bool,
+    pub muted: bool,
+    pub battery_low: bool,
+    /// Signal strength bars, 0-5.
+    pub signal_level: u8,
+}
+
+impl DisplayStatus {
+    pub fn parse(line: &str) -> Result<Self, ScannerError> {
+        let bad = |what: &str| ScannerError::UnexpectedResponse {
+            sent: "STS".to_string(),
+            got: format!("{} ({})", line, what),
+        };
+        let flag = |s: Option<&&str>, what: &str| match s.copied().unwrap_or("") {
+            "" | "0" => Ok(false),
+            "1" => Ok(true),
+            _ => Err(bad(what)),
+        };
+
+        let mut parts = line.split(',');
+        if parts.next() != Some("STS") {
+            return Err(bad("expected STS"));
+        }
+        let form = parts.next().unwrap_or("");
+        if form.is_empty() || !form.chars().all(|c| c == '0' || c == '1') {
+            return Err(bad("display form"));
+        }
+
+        let mut rest: VecDeque<&str> = parts.collect();
+        let mut lines = Vec::with_capacity(form.len());
+        for large in form.chars().map(|c| c == '1') {
+            // Line text may contain commas. The text is a fixed width, so
+            // rejoin pieces if that makes exactly one full line
+            let pieces = full_line_pieces(&rest).unwrap_or(1);
+            if rest.len() < pieces + 1 {
+                return Err(bad("missing line"));
+            }
+            let text = rest.drain(..pieces).collect::<Vec<_>>().join(",");
+            let mode = rest.pop_front().unwrap_or_default();
+            let mut styles: Vec<CharStyle> = mode.chars().map(CharStyle::from_mode).collect();
+            styles.resize(text.chars().count(), CharStyle::Normal);
+            lines.push(DisplayLine { text, styles, large });
+        }
+
+        let signal_level = match rest.get(5).copied().unwrap_or("") {
+            "" => 0,
+            level => level.parse().ok().filter(|&l| l <= 5).ok_or_else(|| bad("signal level"))?,
+        };
+        Ok(Self {
+            lines,
+            squelch_open: flag(rest.front(), "squelch")?,
+            muted: flag(rest.get(1), "mute")?,
+            battery_low: flag(rest.get(2), "battery")?,
+            signal_level,
+        })
+    }
+
+    /// Whether the radio is showing a Close Call hit, labelled "Close Call"
+    /// or "CC" where space is short.
+    pub fn shows_close_call(&self) -> bool {
+        self.lines.iter().any(|line| {
+            let text = line.text.to_ascii_uppercase();
+            text.contains("CLOSE CALL") || text.split_whitespace().any(|word| word == "CC")
+        })
+    }
+}
+
+/// How many of the leading `parts` join up into exactly one line of text,
+/// leaving at least the mode field after them.
+fn full_line_pieces(parts: &VecDeque<&str>) -> Option<usize> {
+    let mut width = 0;
+    for (i, part) in parts.iter().enumerate().take(parts.len().saturating_sub(1)) {
+        width += part.chars().count() + if i > 0 { 1 } else { 0 };
+        if width == LINE_WIDTH {
+            return Some(i + 1);
+        }
+        if width > LINE_WIDTH {
+            break;
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sts() {
+        let status = DisplayStatus::parse(
+            "STS,0100,Bank 12-4567890 ,                ,BHX RADAR       ,*********       ,\
+             123.9750 AM     ,                ,CH052           ,   __           ,1,0,0,,,4,,3",
+        )
+        .unwrap();
+        assert_eq!(status.lines.len(), 4);
+        assert!(!status.lines[0].large && status.lines[1].large);
+        assert_eq!(status.lines[1].text.trim_end(), "BHX RADAR");
+        assert_eq!(status.lines[1].styles[0], CharStyle::Reverse);
+        assert_eq!(status.lines[1].styles[9], CharStyle::Normal);
+        assert_eq!(status.lines[3].styles[3], CharStyle::Underline);
+        assert_eq!(status.lines[3].styles.len(), LINE_WIDTH);
+        assert!(status.squelch_open && !status.muted && !status.battery_low);
+        assert_eq!(status.signal_level, 4);
+
+        // A comma in the text is part of the line, not a field separator
+        let status = DisplayStatus::parse("STS,1,Hello, world    ,                ,0,0").unwrap();
+        assert_eq!(status.lines[0].text, "Hello, world    ");
+        assert_eq!(status.signal_level, 0);
+    }
+
+    #[test]
+    fn test_parse_sts_errors() {
+        assert!(DisplayStatus::parse("GLG,,,,,,,,,,,,").is_err());
+        assert!(DisplayStatus::parse("STS,01x0,A,,B,,C,,D,,0,0").is_err());
+        assert!(DisplayStatus::parse("STS,0000,A,,B,,C").is_err());
+        assert!(DisplayStatus::parse("STS,0,A,,2,0").is_err());
+        assert!(DisplayStatus::parse("STS,0,A,,1,0,0,,,9").is_err());
+    }
+}
@@ -0,0 +1,85 @@
+use std::fmt;
+use std::io;
+
+/// Everything that can go wrong talking to the scanner.
+#[derive(Debug)]
+pub enum ScannerError {
+    /// No complete response arrived for the command before its deadline.
+    Timeout(String),
+    /// The scanner replied `ERR`: the command or a value was malformed.
+    FormatError(String),
+    /// The scanner replied `NG`: the command is not valid in the current mode.
+    NotNow(String),
+    /// The scanner replied, but not with what the command calls for.
+    UnexpectedResponse { sent: String, got: String },
+    /// The link to the scanner has gone away.
+    Disconnected,
+    /// A value was rejected before anything was sent to the scanner.
+    InvalidArgument(String),
+    /// The connected model doesn't have the command, so it wasn't sent.
+    Unsupported { command: String, model: String },
+    Io(io::Error),
+}
+
+impl ScannerError {
+    /// Whether the command is worth another try: the radio was busy, slow
+    /// or unplugged, or its replies got out of step with our commands.
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            ScannerError::Timeout(_)
+                | ScannerError::NotNow(_)
+                | ScannerError::Disconnected
+                | ScannerError::Io(_)
+                | ScannerError::UnexpectedResponse { .. }
+        )
+    }
+}
+
+impl fmt::Display for ScannerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScannerError::Timeout(cmd) => write!(f, "Timed out waiting for a response to {}", cmd),
+            ScannerError::FormatError(cmd) => write!(f, "Scanner rejected {} (ERR)", cmd),
+            ScannerError::NotNow(cmd) => write!(f, "Scanner cannot accept {} now (NG)", cmd),
+            ScannerError::UnexpectedResponse { sent, got } => {
+                write!(f, "Unexpected response to {}: {:?}", sent, got)
+            }
+            ScannerError::Disconnected => write!(f, "Scanner disconnected"),
+            ScannerError::InvalidArgument(msg) => write!(f, "{}", msg),
+            ScannerError::Unsupported { command, model } => {
+                write!(f, "{} is not supported by the {}", command, model)
+            }
+            ScannerError::Io(e) => write!(f, "I/O error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ScannerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScannerError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ScannerError {
+    fn from(e: io::Error) -> Self {
+        let unplugged = matches!(
+            e.kind(),
+            io::ErrorKind::BrokenPipe
+                | io::ErrorKind::NotConnected
+                | io::ErrorKind::UnexpectedEof
+                | io::ErrorKind::ConnectionReset
+                | io::ErrorKind::ConnectionAborted
+        // EIO, ENXIO and ENODEV are what a USB serial device returns once unplugged
+        ) || matches!(e.raw_os_error(), Some(5) | Some(6) | Some(19));
+
+        if unplugged {
+            ScannerError::Disconnected
+        } else {
+            ScannerError::Io(e)
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt;
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+use super::ScannerError;
+
+/// A radio frequency, stored in Hz.
+///
+/// The scanner exchanges frequencies as 8 digits in units of 100 Hz
+/// (`01239750` is 123.975 MHz); people type them in MHz. Displays in MHz
+/// with four decimal places, the scanner's resolution: `123.9750`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub struct Frequency(u32);
+
+/// Hz per unit of the scanner's 8-digit form.
+const SCANNER_UNIT_HZ: u32 = 100;
+
+impl Frequency {
+    pub const fn from_hz(hz: u32) -> Self {
+        Self(hz)
+    }
+
+    /// Like [`Frequency::from_hz`], but refuses Hz the scanner can't
+    /// resolve rather than dropping them.
+    pub fn from_exact_hz(hz: u32) -> Result<Self, ScannerError> {
+        if !hz.is_multiple_of(SCANNER_UNIT_HZ) {
+            return Err(ScannerError::InvalidArgument(format!(
+                "{} Hz is finer than the scanner's {} Hz resolution",
+                hz, SCANNER_UNIT_HZ
+            )));
+        }
+        Ok(Self(hz))
+    }
+
+    pub const fn hz(self) -> u32 {
+        self.0
+    }
+
+    /// Zero is what the scanner reports for an empty channel.
+    pub const fn is_zero(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Parses the scanner's 8-digit form, e.g. `01239750`.
+    pub fn from_scanner(s: &str) -> Result<Self, ScannerError> {
+        if s.len() != 8 || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ScannerError::InvalidArgument(format!(
+                "Expected an 8 digit frequency from the scanner, got {:?}",
+                s
+            )));
+        }
+        // Eight decimal digits always fit
+        let units: u32 = s.parse().unwrap_or_default();
+        Ok(Self(units * SCANNER_UNIT_HZ))
+    }
+
+    /// The scanner's 8-digit form, e.g. `01239750`.
+    pub fn to_scanner(self) -> String {
+        format!("{:08}", self.0 / SCANNER_UNIT_HZ)
+    }
+}
+
+impl fmt::Display for Frequency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let units = self.0 / SCANNER_UNIT_HZ;
+        write!(f, "{}.{:04}", units / 10_000, units % 10_000)
+    }
+}
+
+/// Parses a frequency typed in MHz, such as `118.1`, `123.9750` or
+/// `145.500 MHz`. Precision beyond the scanner's 100 Hz resolution is an
+/// error rather than being silently dropped.
+impl FromStr for Frequency {
+    type Err = ScannerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |why: &str| ScannerError::InvalidArgument(format!("{:?} {}", s, why));
+
+        let text = s.trim();
+        let text = text
+            .strip_suffix("MHz")
+            .or_else(|| text.strip_suffix("mhz"))
+            .unwrap_or(text)
+            .trim_end();
+        let (whole, fraction) = text.split_once('.').unwrap_or((text, ""));
+        let digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if whole.is_empty() || !digits(whole) || !digits(fraction) {
+            return Err(invalid("is not a frequency in MHz"));
+        }
+
+        // MHz has six decimal places of Hz; the scanner only resolves four
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.len() > 4 {
+            return Err(invalid("is more precise than the scanner's 100 Hz steps"));
+        }
+        let mhz: u32 = whole.parse().map_err(|_| invalid("is out of range"))?;
+        let units: u32 = format!("{:0<4}", fraction).parse().unwrap_or_default();
+        mhz.checked_mul(1_000_000)
+            .and_then(|hz| hz.checked_add(units * SCANNER_UNIT_HZ))
+            .map(Self)
+            .ok_or_else(|| invalid("is out of range"))
+    }
+}
+
+impl From<Frequency> for String {
+    fn from(frequency: Frequency) -> Self {
+        frequency.to_string()
+    }
+}
+
+impl TryFrom<String> for Frequency {
+    type Error = ScannerError;
+
+    fn try_from(mhz: String) -> Result<Self, Self::Error> {
+        mhz.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scanner_form_round_trip() {
+        let f = Frequency::from_scanner("01239750").unwrap();
+        assert_eq!(f.hz(), 123_975_000);
+        assert_eq!(f.to_scanner(), "01239750");
+        assert_eq!(f.to_string(), "123.9750");
+        assert_eq!(Frequency::from_scanner("00881000").unwrap().to_string(), "88.1000");
+        assert!(Frequency::from_scanner("00000000").unwrap().is_zero());
+        assert!(Frequency::from_scanner("0123975").is_err());
+        assert!(Frequency::from_scanner("0123975x").is_err());
+
+        assert_eq!(Frequency::from_exact_hz(118_008_300).unwrap().to_scanner(), "01180083");
+        assert!(Frequency::from_exact_hz(118_008_333).is_err());
+    }
+
+    #[test]
+    fn test_parse_mhz() {
+        let parse = |s: &str| s.parse::<Frequency>().map(|f| f.hz());
+        assert_eq!(parse("118.1").unwrap(), 118_100_000);
+        assert_eq!(parse("118").unwrap(), 118_000_000);
+        assert_eq!(parse(" 145.500 MHz").unwrap(), 145_500_000);
+        assert!(parse("446.00625").is_err());
+        assert_eq!(parse("446.0062").unwrap(), 446_006_200);
+        assert_eq!(parse("123.975000").unwrap(), 123_975_000);
+        // Used to be truncated to 1234.5678 without complaint
+        assert!(parse("1234.56789").is_err());
+        assert!(parse("").is_err());
+        assert!(parse(".5").is_err());
+        assert!(parse("12a.5").is_err());
+        assert!(parse("99999").is_err());
+    }
+}
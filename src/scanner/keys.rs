@@ -0,0 +1,210 @@
+use super::{Frequency, RadioMode, ScannerClient, ScannerError};
+
+/// A button on the scanner, as sent in `KEY,[KEY_CODE],[KEY_MODE]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Key {
+    Menu,
+    Func,
+    Hold,
+    Scan,
+    /// Srch/Close Call.
+    Search,
+    Lockout,
+    Priority,
+    Weather,
+    Digit0,
+    Digit1,
+    Digit2,
+    Digit3,
+    Digit4,
+    Digit5,
+    Digit6,
+    Digit7,
+    Digit8,
+    Digit9,
+    /// `.`/No.
+    Decimal,
+    /// E/Yes.
+    Enter,
+    /// The scroll control turned anticlockwise.
+    Left,
+    /// The scroll control turned clockwise.
+    Right,
+    /// The scroll control pressed.
+    Push,
+}
+
+impl Key {
+    pub const ALL: [Key; 23] = [
+        Key::Menu,
+        Key::Func,
+        Key::Hold,
+        Key::Scan,
+        Key::Search,
+        Key::Lockout,
+        Key::Priority,
+        Key::Weather,
+        Key::Digit0,
+        Key::Digit1,
+        Key::Digit2,
+        Key::Digit3,
+        Key::Digit4,
+        Key::Digit5,
+        Key::Digit6,
+        Key::Digit7,
+        Key::Digit8,
+        Key::Digit9,
+        Key::Decimal,
+        Key::Enter,
+        Key::Left,
+        Key::Right,
+        Key::Push,
+    ];
+
+    /// The `KEY_CODE` the radio knows this button by.
+    pub fn code(self) -> char {
+        match self {
+            Key::Menu => 'M',
+            Key::Func => 'F',
+            Key::Hold => 'H',
+            Key::Scan => 'S',
+            Key::Search => 'R',
+            Key::Lockout => 'L',
+            Key::Priority => 'P',
+            Key::Weather => 'W',
+            Key::Digit0 => '0',
+            Key::Digit1 => '1',
+            Key::Digit2 => '2',
+            Key::Digit3 => '3',
+            Key::Digit4 => '4',
+            Key::Digit5 => '5',
+            Key::Digit6 => '6',
+            Key::Digit7 => '7',
+            Key::Digit8 => '8',
+            Key::Digit9 => '9',
+            Key::Decimal => '.',
+            Key::Enter => 'E',
+            Key::Left => '<',
+            Key::Right => '>',
+            Key::Push => '^',
+        }
+    }
+
+    pub fn from_code(code: char) -> Option<Self> {
+        Key::ALL.into_iter().find(|key| key.code() == code)
+    }
+}
+
+/// How a button is pressed (`KEY_MODE`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum KeyAction {
+    #[default]
+    Press,
+    /// Held for a couple of seconds, e.g. Func held to lock the keypad.
+    LongPress,
+    /// Held down until a [`KeyAction::Release`].
+    Hold,
+    Release,
+}
+
+impl KeyAction {
+    pub fn code(self) -> char {
+        match self {
+            KeyAction::Press => 'P',
+            KeyAction::LongPress => 'L',
+            KeyAction::Hold => 'H',
+            KeyAction::Release => 'R',
+        }
+    }
+}
+
+/// Presses that tune the radio to `frequency`: Hold, the frequency in MHz,
+/// then Hold again to listen there. Scan comes first, as Hold while
+/// already holding would resume scanning instead.
+pub fn tune(frequency: Frequency) -> Vec<(Key, KeyAction)> {
+    let mhz = frequency.to_string();
+    let mhz = mhz.trim_end_matches('0').trim_end_matches('.');
+    let mut presses = vec![(Key::Scan, KeyAction::Press), (Key::Hold, KeyAction::Press)];
+    // Display only ever gives digits and a decimal point
+    presses.extend(mhz.chars().filter_map(Key::from_code).map(|key| (key, KeyAction::Press)));
+    presses.push((Key::Hold, KeyAction::Press));
+    presses
+}
+
+impl ScannerClient {
+    pub fn press(&mut self, key: Key, action: KeyAction) -> Result<(), ScannerError> {
+        self.send_set(&format!("KEY,{},{}", key.code(), action.code()))?;
+        if action == KeyAction::Press {
+            self.track_press(key);
+        }
+        Ok(())
+    }
+
+    /// Follows Scan and Hold, and frequencies typed while holding, so a
+    /// reconnect can restore them. Searches are treated as scanning.
+    fn track_press(&mut self, key: Key) {
+        match (key, self.mode) {
+            (Key::Scan, _) => self.mode = RadioMode::Scanning,
+            (Key::Hold, RadioMode::Scanning) => self.mode = RadioMode::Holding(None),
+            // Hold after typing a frequency tunes to it, otherwise it resumes
+            (Key::Hold, RadioMode::Holding(_)) if !self.keypad.is_empty() => {
+                self.mode = RadioMode::Holding(self.keypad.parse().ok());
+            }
+            (Key::Hold, RadioMode::Holding(_)) => self.mode = RadioMode::Scanning,
+            (_, RadioMode::Holding(_)) if key == Key::Decimal || key.code().is_ascii_digit() => {
+                self.keypad.push(key.code());
+                return;
+            }
+            _ => {}
+        }
+        self.keypad.clear();
+    }
+
+    /// Presses each key in turn, stopping at the first the radio refuses.
+    pub fn press_keys(&mut self, presses: &[(Key, KeyAction)]) -> Result<(), ScannerError> {
+        for &(key, action) in presses {
+            self.press(key, action)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use crate::scanner::transport::MemoryTransport;
+    use crate::simulator::Simulator;
+
+    #[test]
+    fn test_key_codes() {
+        for key in Key::ALL {
+            assert_eq!(Key::from_code(key.code()), Some(key));
+        }
+        assert_eq!(Key::from_code('X'), None);
+
+        let keys: String = tune("145.500".parse().unwrap()).iter().map(|(k, _)| k.code()).collect();
+        assert_eq!(keys, "SH145.5H");
+        let keys: String = tune("27".parse().unwrap()).iter().map(|(k, _)| k.code()).collect();
+        assert_eq!(keys, "SH27H");
+    }
+
+    #[test]
+    fn test_tune_macro() {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let log = sent.clone();
+        let mut sim = Simulator::new();
+        let mut client = ScannerClient::new(Box::new(MemoryTransport::new(move |cmd| {
+            log.lock().unwrap().push(cmd.to_string());
+            Some(sim.handle(cmd))
+        })));
+
+        client.press(Key::Hold, KeyAction::Press).unwrap();
+        client.press_keys(&tune("145.5".parse().unwrap())).unwrap();
+        assert_eq!(sent.lock().unwrap()[1..4], ["KEY,S,P", "KEY,H,P", "KEY,1,P"]);
+        assert!(client.send_command("GLG").unwrap().starts_with("GLG,01455000,"));
+
+        client.press(Key::Func, KeyAction::LongPress).unwrap();
+        assert!(sent.lock().unwrap().contains(&"KEY,F,L".to_string()));
+    }
+}
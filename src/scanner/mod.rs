@@ -0,0 +1,661 @@
+pub mod actor;
+pub mod backup;
+pub mod capture;
+pub mod channel;
+pub mod close_call;
+pub mod detect;
+pub mod display;
+pub mod error;
+pub mod frequency;
+pub mod keys;
+pub mod model;
+pub mod program;
+pub mod search;
+pub mod settings;
+pub mod status;
+pub mod tone;
+pub mod transport;
+
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+pub use error::ScannerError;
+pub use frequency::Frequency;
+pub use program::ProgramSession;
+use capture::Recorder;
+use display::DisplayStatus;
+use keys::{Key, KeyAction};
+use model::ScannerModel;
+use transport::{Connector, ScannerTransport};
+
+/// How many unrelated lines `send_command` will discard while waiting for
+/// the reply to its own command.
+const MAX_STRAY_LINES: usize = 4;
+
+/// Commands whose first argument is an index: a read (`CIN,12`) is answered
+/// with the index echoed back (`CIN,12,...`), a write with `OK`.
+const INDEXED_COMMANDS: &[&str] = &["CIN", "CSP"];
+
+/// How long to wait for a reply unless [`TIMEOUTS`] says otherwise.
+const DEFAULT_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Commands known to take longer than [`DEFAULT_TIMEOUT`] to answer.
+const TIMEOUTS: &[(&str, Duration)] = &[
+    // Clearing all memory is documented as taking time
+    ("CLR", Duration::from_secs(30)),
+    // The radio redraws its display while switching modes
+    ("PRG", Duration::from_secs(1)),
+    ("EPG", Duration::from_secs(1)),
+];
+
+/// What the radio was last set doing, so [`ScannerClient::reconnect`] can
+/// put it back.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RadioMode {
+    #[default]
+    Scanning,
+    /// Scan Hold, on the frequency typed in if it was tuned from the keypad.
+    Holding(Option<Frequency>),
+    /// In a [`ProgramSession`], which scans again afterwards if
+    /// `resume_scan` is set.
+    Program { resume_scan: bool },
+}
+
+pub struct ScannerClient {
+    // None once the link has dropped, until `reconnect` succeeds
+    transport: Option<Box<dyn ScannerTransport>>,
+    connector: Option<Connector>,
+    // Caller overrides of the TIMEOUTS table
+    timeouts: HashMap<String, Duration>,
+    recorder: Option<Recorder>,
+    // Set by `detect_model`; until then every command is allowed
+    model: Option<ScannerModel>,
+    mode: RadioMode,
+    // Digits typed since Hold was pressed, tuning the radio on the next Hold
+    keypad: String,
+}
+
+impl ScannerClient {
+    #[allow(dead_code)]
+    pub fn new(transport: Box<dyn ScannerTransport>) -> Self {
+        Self {
+            transport: Some(transport),
+            connector: None,
+            timeouts: HashMap::new(),
+            recorder: None,
+            model: None,
+            mode: RadioMode::default(),
+            keypad: String::new(),
+        }
+    }
+
+    /// Connects with `connector`, keeping it to reconnect if the link drops.
+    pub fn with_connector(mut connector: Connector) -> Result<Self, ScannerError> {
+        let transport = connector()?;
+        Ok(Self {
+            transport: Some(transport),
+            connector: Some(connector),
+            timeouts: HashMap::new(),
+            recorder: None,
+            model: None,
+            mode: RadioMode::default(),
+            keypad: String::new(),
+        })
+    }
+
+    /// Opens the device described by `device`, see [`transport::open`],
+    /// and detects which model it is. A scanner that doesn't answer yet is
+    /// still opened; [`ScannerClient::model`] tries again later.
+    pub fn open(device: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut client = Self::with_connector(transport::connector(device))?;
+        match client.detect_model() {
+            Ok(model) => tracing::info!("Connected to {}", model),
+            Err(e) => tracing::warn!("Could not detect the scanner model: {}", e),
+        }
+        Ok(client)
+    }
+
+    /// Appends every exchange from now on to the JSONL capture file at
+    /// `path`, see [`capture::Exchange`].
+    pub fn record_to(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.recorder = Some(Recorder::create(path)?);
+        Ok(())
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.transport.is_some()
+    }
+
+    /// What the radio was last set doing.
+    #[allow(dead_code)]
+    pub fn mode(&self) -> RadioMode {
+        self.mode
+    }
+
+    /// Reopens the link after a disconnect and puts the radio back in the
+    /// [`RadioMode`] it was in. A [`ProgramSession`] cannot outlive the link,
+    /// so an interrupted one is left, as it would have been, rather than
+    /// re-entered.
+    pub fn reconnect(&mut self) -> Result<(), ScannerError> {
+        let connector = self.connector.as_mut().ok_or(ScannerError::Disconnected)?;
+        self.transport = Some(connector()?);
+
+        if let Err(e) = self.restore_mode() {
+            // Probably still booting; try again later
+            self.transport = None;
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    fn restore_mode(&mut self) -> Result<(), ScannerError> {
+        if let RadioMode::Program { resume_scan } = self.mode {
+            tracing::warn!("A program mode session was interrupted by the disconnect");
+            // NG if a power cycle has already taken the radio out of it
+            match self.send_set("EPG") {
+                Ok(()) | Err(ScannerError::NotNow(_)) => {}
+                Err(e) => return Err(e),
+            }
+            self.mode = if resume_scan { RadioMode::Scanning } else { RadioMode::Holding(None) };
+        }
+        match self.mode {
+            // After a power cycle the radio may also be left in Scan Hold
+            RadioMode::Scanning => self.press(Key::Scan, KeyAction::Press),
+            RadioMode::Holding(Some(frequency)) => self.press_keys(&keys::tune(frequency)),
+            // Nothing to tune back to, so leave it where it is; MDL just checks
+            // the radio is answering
+            RadioMode::Holding(None) | RadioMode::Program { .. } => self.query("MDL").map(|_| ()),
+        }
+    }
+
+    /// Overrides how long to wait for replies to `command` (e.g. `CLR`).
+    #[allow(dead_code)]
+    pub fn set_timeout(&mut self, command: &str, timeout: Duration) {
+        self.timeouts.insert(command.to_string(), timeout);
+    }
+
+    /// How long to wait for the reply to `command`: a caller override, else
+    /// the [`TIMEOUTS`] table, else [`DEFAULT_TIMEOUT`].
+    pub fn timeout_for(&self, command: &str) -> Duration {
+        self.timeouts
+            .get(command)
+            .copied()
+            .or_else(|| TIMEOUTS.iter().find(|(name, _)| *name == command).map(|(_, t)| *t))
+            .unwrap_or(DEFAULT_TIMEOUT)
+    }
+
+    /// Puts the scanner in program mode, see [`ProgramSession`].
+    pub fn program(&mut self) -> Result<ProgramSession<'_>, ScannerError> {
+        ProgramSession::enter(self)
+    }
+
+    /// Sends `cmd` and returns the scanner's reply line.
+    ///
+    /// The reply must echo the command name (`CIN,...` for `CIN,12`), and
+    /// the index for [`INDEXED_COMMANDS`]. Lines that don't, such as a late
+    /// reply to an earlier command that timed out, are logged and discarded,
+    /// up to [`MAX_STRAY_LINES`] of them.
+    /// `ERR` and `NG` replies are returned as [`ScannerError::FormatError`]
+    /// and [`ScannerError::NotNow`].
+    ///
+    /// Program mode commands (`PRG`, `CIN`, `SCG`, ...) are refused with
+    /// [`ScannerError::InvalidArgument`]; use [`ScannerClient::program`].
+    /// Commands the detected model lacks are refused with
+    /// [`ScannerError::Unsupported`].
+    pub fn send_command(&mut self, cmd: &str) -> Result<String, ScannerError> {
+        let timeout = self.timeout_for(command_name(cmd));
+        self.send_command_with_timeout(cmd, timeout)
+    }
+
+    /// [`ScannerClient::send_command`] with an explicit reply timeout.
+    pub fn send_command_with_timeout(
+        &mut self,
+        cmd: &str,
+        timeout: Duration,
+    ) -> Result<String, ScannerError> {
+        let name = command_name(cmd);
+        if program::PROGRAM_COMMANDS.contains(&name) {
+            return Err(ScannerError::InvalidArgument(format!(
+                "{} is only available in a program session",
+                name
+            )));
+        }
+        self.exchange_or_drop(cmd, timeout)
+    }
+
+    /// `send_command` without the program mode check.
+    fn command(&mut self, cmd: &str) -> Result<String, ScannerError> {
+        let timeout = self.timeout_for(command_name(cmd));
+        self.exchange_or_drop(cmd, timeout)
+    }
+
+    /// Runs `exchange`, recording it if capturing and dropping the transport
+    /// if the link has gone.
+    fn exchange_or_drop(&mut self, cmd: &str, timeout: Duration) -> Result<String, ScannerError> {
+        self.check_supported(command_name(cmd))?;
+        let start = Instant::now();
+        let mut stray = Vec::new();
+        let result = self.exchange(cmd, timeout, &mut stray);
+        if let Some(recorder) = self.recorder.as_mut()
+            && let Err(e) = recorder.record(cmd, &result, stray, start.elapsed())
+        {
+            tracing::warn!("Failed to record exchange: {}", e);
+        }
+        if let Err(ScannerError::Disconnected) = result {
+            self.transport = None;
+        }
+        result
+    }
+
+    fn exchange(
+        &mut self,
+        cmd: &str,
+        timeout: Duration,
+        stray: &mut Vec<String>,
+    ) -> Result<String, ScannerError> {
+        let transport = self.transport.as_mut().ok_or(ScannerError::Disconnected)?;
+        transport.write_line(cmd)?;
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let response = match transport.read_line(remaining) {
+                Err(e) if e.kind() == io::ErrorKind::TimedOut => {
+                    return Err(ScannerError::Timeout(cmd.to_string()));
+                }
+                r => r?,
+            };
+
+            match response.as_str() {
+                "ERR" => return Err(ScannerError::FormatError(cmd.to_string())),
+                "NG" => return Err(ScannerError::NotNow(cmd.to_string())),
+                _ if answers(cmd, &response) => return Ok(response),
+                _ if stray.len() < MAX_STRAY_LINES => {
+                    tracing::warn!("Discarding stray line {:?} while waiting for {}", response, cmd);
+                    stray.push(response);
+                }
+                _ => return Err(unexpected(cmd, response)),
+            }
+        }
+    }
+
+    /// Sends a setting command and checks for the `<CMD>,OK` acknowledgement.
+    fn send_set(&mut self, cmd: &str) -> Result<(), ScannerError> {
+        let response = self.command(cmd)?;
+        if response == format!("{},OK", command_name(cmd)) {
+            Ok(())
+        } else {
+            Err(unexpected(cmd, response))
+        }
+    }
+
+    /// Sends a query and returns the fields after the `<CMD>,` prefix.
+    fn query(&mut self, cmd: &str) -> Result<Vec<String>, ScannerError> {
+        // send_command has already checked the prefix
+        let response = self.command(cmd)?;
+        Ok(response.split(',').skip(1).map(str::to_string).collect())
+    }
+
+    fn query_level(&mut self, cmd: &str) -> Result<u8, ScannerError> {
+        let fields = self.query(cmd)?;
+        match fields.first().map(|f| f.parse::<u8>()) {
+            Some(Ok(level)) if fields.len() == 1 => Ok(level),
+            _ => Err(unexpected(cmd, format!("{},{}", cmd, fields.join(",")))),
+        }
+    }
+
+    pub fn get_volume(&mut self) -> Result<u8, ScannerError> {
+        self.query_level("VOL")
+    }
+
+    #[allow(dead_code)]
+    pub fn set_volume(&mut self, level: u8) -> Result<(), ScannerError> {
+        if level > 15 {
+            return Err(ScannerError::InvalidArgument(
+                "Volume level must be between 0 and 15".to_string(),
+            ));
+        }
+        self.send_set(&format!("VOL,{}", level))
+    }
+
+    pub fn get_squelch(&mut self) -> Result<u8, ScannerError> {
+        self.query_level("SQL")
+    }
+
+    pub fn set_squelch(&mut self, level: u8) -> Result<(), ScannerError> {
+        if level > 15 {
+            return Err(ScannerError::InvalidArgument(
+                "Squelch level must be between 0 and 15".to_string(),
+            ));
+        }
+        self.send_set(&format!("SQL,{}", level))
+    }
+
+    /// What the radio's LCD is showing, menus included.
+    pub fn display_status(&mut self) -> Result<DisplayStatus, ScannerError> {
+        let response = self.send_command("STS")?;
+        DisplayStatus::parse(&response)
+    }
+}
+
+/// The command name a line starts with, e.g. `CIN` for `CIN,12`.
+fn command_name(line: &str) -> &str {
+    line.split(',').next().unwrap_or(line)
+}
+
+/// Whether `response` is the reply to `cmd` rather than a late reply to
+/// something sent earlier.
+fn answers(cmd: &str, response: &str) -> bool {
+    let name = command_name(cmd);
+    if command_name(response) != name {
+        return false;
+    }
+    if !INDEXED_COMMANDS.contains(&name) {
+        return true;
+    }
+    let sent: Vec<&str> = cmd.split(',').collect();
+    let got = response.split(',').nth(1);
+    match sent.as_slice() {
+        [_, index] => got == Some(*index),
+        [_, _, _, ..] => got == Some("OK"),
+        _ => true,
+    }
+}
+
+fn unexpected(sent: &str, got: String) -> ScannerError {
+    ScannerError::UnexpectedResponse {
+        sent: sent.to_string(),
+        got,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+    use transport::MemoryTransport;
+    use crate::simulator::Simulator;
+    use channel::ChannelInfo;
+
+    /// A transport whose cable can be pulled out from under it.
+    struct Unpluggable {
+        plugged: Arc<AtomicBool>,
+        sent: Arc<Mutex<Vec<String>>>,
+        pending: Option<String>,
+    }
+
+    impl ScannerTransport for Unpluggable {
+        fn write_line(&mut self, line: &str) -> io::Result<()> {
+            if !self.plugged.load(Ordering::SeqCst) {
+                return Err(io::Error::from_raw_os_error(5));
+            }
+            self.sent.lock().unwrap().push(line.to_string());
+            let name = command_name(line);
+            self.pending = Some(format!("{},OK", name));
+            Ok(())
+        }
+
+        fn read_line(&mut self, _timeout: Duration) -> io::Result<String> {
+            self.pending.take().ok_or(io::ErrorKind::TimedOut.into())
+        }
+    }
+
+    #[test]
+    fn test_send_command_over_memory_transport() {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let log = sent.clone();
+        let mut client = ScannerClient::new(Box::new(MemoryTransport::new(move |cmd| {
+            log.lock().unwrap().push(cmd.to_string());
+            match cmd {
+                "VOL" => Some("VOL,6".to_string()),
+                "SQL,3" => Some("SQL,OK".to_string()),
+                _ => Some("ERR".to_string()),
+            }
+        })));
+
+        assert_eq!(client.get_volume().unwrap(), 6);
+        client.set_squelch(3).unwrap();
+        assert!(matches!(client.set_squelch(16), Err(ScannerError::InvalidArgument(_))));
+        assert_eq!(*sent.lock().unwrap(), vec!["VOL", "SQL,3"]);
+    }
+
+    #[test]
+    fn test_error_responses() {
+        let mut client = ScannerClient::new(Box::new(MemoryTransport::new(|cmd| match cmd {
+            "KEY,S,P" => Some("NG".to_string()),
+            "VOL" => Some("VOL,loud".to_string()),
+            "SQL" => None,
+            _ => Some("ERR".to_string()),
+        })));
+
+        assert!(matches!(client.send_command("KEY,S,P"), Err(ScannerError::NotNow(_))));
+        assert!(matches!(client.send_command("XYZ"), Err(ScannerError::FormatError(_))));
+        assert!(matches!(client.get_squelch(), Err(ScannerError::Timeout(_))));
+        assert!(matches!(
+            client.get_volume(),
+            Err(ScannerError::UnexpectedResponse { ref got, .. }) if got == "VOL,loud"
+        ));
+    }
+
+    #[test]
+    fn test_stray_lines_are_discarded() {
+        let mut transport = MemoryTransport::new(|cmd| match cmd {
+            "MDL" => Some("MDL,BC125AT".to_string()),
+            "VOL" => Some("VOL,6".to_string()),
+            _ => None,
+        });
+        // A late GLG reply from a poll that had already timed out
+        transport.push_line("GLG,01239750,AM,,0,,,BHX RADAR,1,0,,52,");
+        let mut client = ScannerClient::new(Box::new(transport));
+
+        assert_eq!(client.send_command("MDL").unwrap(), "MDL,BC125AT");
+        assert_eq!(client.get_volume().unwrap(), 6);
+    }
+
+    #[test]
+    fn test_late_reply_for_earlier_index_is_discarded() {
+        let mut sim = Simulator::new();
+        let mut transport = MemoryTransport::new(move |cmd| Some(sim.handle(cmd)));
+        // CIN,1 timed out earlier, and its reply turns up now
+        transport.push_line("CIN,1,BHX APPR,01189100,AM,0,2,0,0");
+        transport.push_line("CSP,1,02500000,02799500");
+        let mut client = ScannerClient::new(Box::new(transport));
+
+        let mut session = client.program().unwrap();
+        assert_eq!(session.get_channel(2).unwrap().name.as_str(), "BHX TOWER");
+        assert_eq!(session.get_search_range(2).unwrap().index, 2);
+
+        assert!(answers("CIN,12", "CIN,12,A,01563000,FM,0,2,0,0"));
+        assert!(!answers("CIN,13", "CIN,12,A,01563000,FM,0,2,0,0"));
+        assert!(answers("CIN,13,A,01563000,FM,0,2,0,0", "CIN,OK"));
+        assert!(!answers("CIN,13,A,01563000,FM,0,2,0,0", "CIN,12,A,01563000,FM,0,2,0,0"));
+        assert!(answers("VOL", "VOL,6"));
+        assert!(!answers("VOL", "GLG,,,,,,,,,,,,"));
+    }
+
+    #[test]
+    fn test_too_many_stray_lines() {
+        let mut transport = MemoryTransport::new(|_| Some("MDL,BC125AT".to_string()));
+        for _ in 0..=MAX_STRAY_LINES {
+            transport.push_line("GLG,,,,,,,,,,,,");
+        }
+        let mut client = ScannerClient::new(Box::new(transport));
+
+        assert!(matches!(
+            client.send_command("MDL"),
+            Err(ScannerError::UnexpectedResponse { ref got, .. }) if got.starts_with("GLG")
+        ));
+        // The real reply is still queued and is picked up next time
+        assert!(client.send_command("MDL").is_ok());
+    }
+
+    #[test]
+    fn test_reconnect_after_dropped_session_resumes_scanning() {
+        let plugged = Arc::new(AtomicBool::new(true));
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let (p, log) = (plugged.clone(), sent.clone());
+        let mut client = ScannerClient::with_connector(Box::new(move || {
+            if !p.load(Ordering::SeqCst) {
+                return Err(io::ErrorKind::NotFound.into());
+            }
+            Ok(Box::new(Unpluggable { plugged: p.clone(), sent: log.clone(), pending: None }))
+        }))
+        .unwrap();
+
+        {
+            let mut session = client.program().unwrap().resume_scan();
+            plugged.store(false, Ordering::SeqCst);
+            assert!(matches!(session.delete_channel(1), Err(ScannerError::Disconnected)));
+        }
+        assert!(!client.is_connected());
+        assert!(client.reconnect().is_err());
+
+        plugged.store(true, Ordering::SeqCst);
+        client.reconnect().unwrap();
+        assert!(client.is_connected());
+        // The interrupted session is ended as it would have been
+        assert_eq!(*sent.lock().unwrap(), vec!["PRG", "EPG", "KEY,S,P"]);
+        assert_eq!(client.mode(), RadioMode::Scanning);
+    }
+
+    #[test]
+    fn test_reconnect_restores_hold() {
+        let plugged = Arc::new(AtomicBool::new(true));
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let (p, log) = (plugged.clone(), sent.clone());
+        let mut client = ScannerClient::with_connector(Box::new(move || {
+            Ok(Box::new(Unpluggable { plugged: p.clone(), sent: log.clone(), pending: None }))
+        }))
+        .unwrap();
+        let unplug = |client: &mut ScannerClient| {
+            plugged.store(false, Ordering::SeqCst);
+            assert!(client.get_volume().is_err());
+            plugged.store(true, Ordering::SeqCst);
+            sent.lock().unwrap().clear();
+            client.reconnect().unwrap();
+            sent.lock().unwrap().clone()
+        };
+
+        // Held mid-scan: left where it is
+        client.press(Key::Hold, KeyAction::Press).unwrap();
+        assert_eq!(client.mode(), RadioMode::Holding(None));
+        assert_eq!(unplug(&mut client), vec!["MDL"]);
+
+        // Tuned from the keypad: tuned there again
+        client.press_keys(&keys::tune("145.5".parse().unwrap())).unwrap();
+        assert_eq!(client.mode(), RadioMode::Holding(Some("145.5".parse().unwrap())));
+        let restored = unplug(&mut client);
+        assert_eq!(restored[..3], ["KEY,S,P", "KEY,H,P", "KEY,1,P"]);
+        assert_eq!(client.mode(), RadioMode::Holding(Some("145.5".parse().unwrap())));
+
+        client.press(Key::Hold, KeyAction::Press).unwrap();
+        assert_eq!(client.mode(), RadioMode::Scanning);
+        assert_eq!(unplug(&mut client), vec!["KEY,S,P"]);
+    }
+
+    #[test]
+    fn test_timeouts() {
+        let mut client = ScannerClient::new(Box::new(MemoryTransport::new(|_| None)));
+        assert_eq!(client.timeout_for("GLG"), DEFAULT_TIMEOUT);
+        assert_eq!(client.timeout_for("CLR"), Duration::from_secs(30));
+
+        client.set_timeout("GLG", Duration::from_millis(20));
+        assert_eq!(client.timeout_for("GLG"), Duration::from_millis(20));
+
+        let start = Instant::now();
+        assert!(matches!(
+            client.send_command_with_timeout("MDL", Duration::from_millis(10)),
+            Err(ScannerError::Timeout(_))
+        ));
+        assert!(start.elapsed() < DEFAULT_TIMEOUT);
+    }
+
+    #[test]
+    fn test_get_channels_reports_progress() {
+        let mut sim = Simulator::new();
+        let mut client = ScannerClient::new(Box::new(MemoryTransport::new(move |cmd| Some(sim.handle(cmd)))));
+        let mut session = client.program().unwrap();
+
+        let mut steps = Vec::new();
+        let channels = session
+            .get_channels(&[1, 2, 3], |done, total| steps.push((done, total)))
+            .unwrap();
+        assert_eq!(channels[1].to_command().unwrap(), "CIN,2,BHX TOWER,01183000,AM,0,2,0,0");
+        assert_eq!(steps, vec![(1, 3), (2, 3), (3, 3)]);
+
+        session.clear_memory().unwrap();
+        assert!(session.get_channel(2).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_program_session() {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let log = sent.clone();
+        let mut sim = Simulator::new();
+        let mut client = ScannerClient::new(Box::new(MemoryTransport::new(move |cmd| {
+            log.lock().unwrap().push(cmd.to_string());
+            Some(sim.handle(cmd))
+        })));
+
+        assert!(matches!(client.send_command("CIN,1"), Err(ScannerError::InvalidArgument(_))));
+        assert!(matches!(client.send_command("PRG"), Err(ScannerError::InvalidArgument(_))));
+
+        let banks = client.program().unwrap().resume_scan().get_scan_banks().unwrap();
+        assert_eq!(banks, [true; 10]);
+        {
+            let mut session = client.program().unwrap();
+            let channel = ChannelInfo::parse("CIN,10,TEST,01455000,FM,0,2,0,0").unwrap();
+            session.set_channel(&channel).unwrap();
+            assert_eq!(session.get_channel(10).unwrap(), channel);
+            // An error part way through still leaves program mode
+            assert!(matches!(session.get_channel(501), Err(ScannerError::InvalidArgument(_))));
+            assert!(matches!(session.set_scan_banks(&[true; 12]), Err(ScannerError::InvalidArgument(_))));
+            assert!(matches!(session.client.command("XYZ"), Err(ScannerError::FormatError(_))));
+        }
+        assert_eq!(
+            *sent.lock().unwrap(),
+            vec![
+                "PRG",
+                "SCG",
+                "EPG",
+                "KEY,S,P",
+                "PRG",
+                "CIN,10,TEST,01455000,FM,0,2,0,0",
+                "CIN,10",
+                "XYZ",
+                "EPG",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_global_lockouts() {
+        let mut sim = Simulator::new();
+        let mut client = ScannerClient::new(Box::new(MemoryTransport::new(move |cmd| {
+            Some(sim.handle(cmd))
+        })));
+        assert!(matches!(client.send_command("GLF"), Err(ScannerError::InvalidArgument(_))));
+
+        let marine: Frequency = "156.8".parse().unwrap();
+        let pmr: Frequency = "446.0062".parse().unwrap();
+        {
+            let mut session = client.program().unwrap();
+            assert!(session.get_lockouts(|_| {}).unwrap().is_empty());
+            session.lock_out(marine).unwrap();
+            session.lock_out(pmr).unwrap();
+            assert!(matches!(
+                session.lock_out(Frequency::default()),
+                Err(ScannerError::InvalidArgument(_))
+            ));
+        }
+        let mut found = Vec::new();
+        let lockouts = client.program().unwrap().get_lockouts(|n| found.push(n)).unwrap();
+        assert_eq!(lockouts, vec![marine, pmr]);
+        assert_eq!(found, vec![1, 2]);
+
+        client.program().unwrap().unlock(marine).unwrap();
+        assert_eq!(client.program().unwrap().get_lockouts(|_| {}).unwrap(), vec![pmr]);
+    }
+}
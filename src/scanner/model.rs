@@ -0,0 +1,363 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+use super::{Frequency, ScannerClient, ScannerError};
+
+/// How far a frequency may be from a step and still be on it. The scanner
+/// only resolves 100 Hz, so 8.33 kHz channels are rounded.
+const STEP_TOLERANCE_HZ: u64 = 50;
+
+/// A channel step. Airband 8.33 kHz spacing is really 25/3 kHz, so a step
+/// is kept as Hz over a divisor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Step {
+    hz: u32,
+    divisor: u32,
+}
+
+impl Step {
+    pub const fn khz(khz: u32) -> Self {
+        Self { hz: khz * 1000, divisor: 1 }
+    }
+
+    pub const fn hz(hz: u32) -> Self {
+        Self { hz, divisor: 1 }
+    }
+
+    /// The 8.33 kHz airband channel spacing.
+    pub const AIRBAND: Step = Step { hz: 25_000, divisor: 3 };
+
+    /// Whether `frequency` is a whole number of steps, to within the
+    /// scanner's resolution.
+    pub fn fits(self, frequency: Frequency) -> bool {
+        // Scaled by the divisor to stay in whole numbers
+        let step = u64::from(self.hz);
+        let offset = u64::from(frequency.hz()) * u64::from(self.divisor) % step;
+        offset.min(step - offset) <= STEP_TOLERANCE_HZ * u64::from(self.divisor)
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let khz = format!("{:.2}", self.hz as f64 / self.divisor as f64 / 1000.0);
+        write!(f, "{} kHz", khz.trim_end_matches('0').trim_end_matches('.'))
+    }
+}
+
+/// A receive band, lowest and highest frequency inclusive, and the channel
+/// steps used in it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Band {
+    pub lower: Frequency,
+    pub upper: Frequency,
+    pub steps: &'static [Step],
+}
+
+impl Band {
+    pub fn contains(&self, frequency: Frequency) -> bool {
+        (self.lower..=self.upper).contains(&frequency)
+    }
+}
+
+/// What a model can do.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Capabilities {
+    pub channel_count: u16,
+    pub bank_count: usize,
+    /// In frequency order. Neighbouring bands may share their limit.
+    pub bands: &'static [Band],
+    /// Which of the [`REVERSE_ENGINEERED`] commands the model answers.
+    pub extra_commands: &'static [&'static str],
+}
+
+impl Capabilities {
+    pub fn channels_per_bank(&self) -> u32 {
+        u32::from(self.channel_count) / self.bank_count as u32
+    }
+
+    /// The bank holding channel `index`, both counting from 1.
+    pub fn bank_of(&self, index: u32) -> u32 {
+        (index - 1) / self.channels_per_bank() + 1
+    }
+
+    /// The channels in `bank`, both counting from 1.
+    pub fn bank_channels(&self, bank: u32) -> RangeInclusive<u32> {
+        let per_bank = self.channels_per_bank();
+        (bank - 1) * per_bank + 1..=bank * per_bank
+    }
+
+    /// Whether the model understands `command`. Everything in Uniden's
+    /// published protocol is assumed to work.
+    pub fn supports(&self, command: &str) -> bool {
+        !REVERSE_ENGINEERED.contains(&command) || self.extra_commands.contains(&command)
+    }
+
+    /// Checks that `frequency` is in one of the model's bands.
+    pub fn check_band(&self, frequency: Frequency) -> Result<(), ScannerError> {
+        if self.bands.iter().any(|band| band.contains(frequency)) {
+            return Ok(());
+        }
+        // Bands that only differ in their steps are listed as one
+        let mut ranges: Vec<(Frequency, Frequency)> = Vec::new();
+        for band in self.bands {
+            match ranges.last_mut() {
+                Some((_, upper)) if *upper == band.lower => *upper = band.upper,
+                _ => ranges.push((band.lower, band.upper)),
+            }
+        }
+        let ranges: Vec<String> = ranges
+            .iter()
+            .map(|&(lower, upper)| format!("{}-{}", in_mhz(lower), in_mhz(upper)))
+            .collect();
+        Err(ScannerError::InvalidArgument(format!(
+            "{} MHz is outside the scanner's bands ({} MHz)",
+            frequency,
+            ranges.join(", ")
+        )))
+    }
+
+    /// Checks that `frequency` is in one of the model's bands and on one of
+    /// that band's channel steps.
+    pub fn check_frequency(&self, frequency: Frequency) -> Result<(), ScannerError> {
+        self.check_band(frequency)?;
+        let mut bands = self.bands.iter().filter(|band| band.contains(frequency));
+        if bands.clone().any(|band| band.steps.iter().any(|step| step.fits(frequency))) {
+            return Ok(());
+        }
+        let steps: Vec<String> = bands
+            .next()
+            .map_or(&[][..], |band| band.steps)
+            .iter()
+            .map(Step::to_string)
+            .collect();
+        Err(ScannerError::InvalidArgument(format!(
+            "{} MHz is not on a channel step ({})",
+            frequency,
+            steps.join(", ")
+        )))
+    }
+}
+
+/// `frequency` in MHz, without the decimals if it is a whole number.
+fn in_mhz(frequency: Frequency) -> String {
+    if frequency.hz().is_multiple_of(1_000_000) {
+        (frequency.hz() / 1_000_000).to_string()
+    } else {
+        frequency.to_string()
+    }
+}
+
+/// Commands missing from Uniden's protocol document, worked out from the
+/// BC125AT itself. Other models may not have them.
+pub const REVERSE_ENGINEERED: &[&str] = &["GLG", "STS"];
+
+const fn mhz(mhz: u32) -> Frequency {
+    Frequency::from_hz(mhz * 1_000_000)
+}
+
+const fn band(lower: u32, upper: u32, steps: &'static [Step]) -> Band {
+    Band { lower: mhz(lower), upper: mhz(upper), steps }
+}
+
+/// Land mobile and amateur channel steps.
+const LAND_MOBILE: &[Step] = &[Step::khz(5), Step::hz(6250), Step::hz(7500), Step::hz(12_500)];
+/// Airband channels are 25 kHz apart, or 8.33 kHz in busy airspace.
+const AIRBAND: &[Step] = &[Step::khz(25), Step::AIRBAND];
+
+const BC125AT: Capabilities = Capabilities {
+    channel_count: 500,
+    bank_count: 10,
+    bands: &[
+        band(25, 54, LAND_MOBILE),
+        band(108, 137, AIRBAND),
+        band(137, 174, LAND_MOBILE),
+        band(225, 380, LAND_MOBILE),
+        band(400, 512, LAND_MOBILE),
+    ],
+    extra_commands: &["GLG", "STS"],
+};
+
+/// The BC125AT's successor, which adds VHF low to 88 MHz and FM broadcast.
+const UBC126AT: Capabilities = Capabilities {
+    bands: &[
+        band(25, 88, LAND_MOBILE),
+        band(88, 108, &[Step::khz(50)]),
+        band(108, 137, AIRBAND),
+        band(137, 174, LAND_MOBILE),
+        band(225, 380, LAND_MOBILE),
+        band(400, 512, LAND_MOBILE),
+    ],
+    ..BC125AT
+};
+
+/// For a model that answers `MDL` but isn't in [`MODELS`]: the BC125AT
+/// memory layout, but nothing beyond the published protocol.
+pub const UNKNOWN: Capabilities = Capabilities {
+    extra_commands: &[],
+    ..BC125AT
+};
+
+/// Models known to speak the BC125AT protocol, by their `MDL` reply.
+const MODELS: &[(&str, &Capabilities)] = &[
+    ("BC125AT", &BC125AT),
+    // The UK/EU version of the BC125AT
+    ("UBC125XLT", &BC125AT),
+    ("UBC126AT", &UBC126AT),
+];
+
+/// Whether `name`, as reported by `MDL`, is a model we know how to drive.
+pub fn is_known(name: &str) -> bool {
+    MODELS.iter().any(|(model, _)| *model == name)
+}
+
+/// The scanner on the other end of the link, from `MDL` and `VER`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScannerModel {
+    pub name: String,
+    pub firmware: String,
+    pub capabilities: &'static Capabilities,
+}
+
+impl ScannerModel {
+    pub fn new(name: &str, firmware: &str) -> Self {
+        let capabilities = MODELS
+            .iter()
+            .find(|(model, _)| *model == name)
+            .map_or(&UNKNOWN, |(_, capabilities)| *capabilities);
+        Self {
+            name: name.to_string(),
+            firmware: firmware.to_string(),
+            capabilities,
+        }
+    }
+
+    pub fn is_known(&self) -> bool {
+        is_known(&self.name)
+    }
+}
+
+impl fmt::Display for ScannerModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.name, self.firmware)
+    }
+}
+
+impl ScannerClient {
+    /// Asks the scanner what it is with `MDL` and `VER`. From then on,
+    /// commands the model doesn't have are refused with
+    /// [`ScannerError::Unsupported`] instead of being sent.
+    pub fn detect_model(&mut self) -> Result<&ScannerModel, ScannerError> {
+        let name = self.query("MDL")?.join(",");
+        let firmware = self.query("VER")?.join(",");
+        let model = ScannerModel::new(&name, &firmware);
+        if !model.is_known() {
+            tracing::warn!("Unknown scanner model {}; GLG and STS are disabled", name);
+        }
+        Ok(self.model.insert(model))
+    }
+
+    /// The detected model, detecting it now if that hasn't been done yet.
+    pub fn model(&mut self) -> Result<&ScannerModel, ScannerError> {
+        match self.model {
+            Some(ref model) => Ok(model),
+            None => self.detect_model(),
+        }
+    }
+
+    /// What the detected model can do, or [`UNKNOWN`] before detection.
+    pub fn capabilities(&self) -> &'static Capabilities {
+        self.model.as_ref().map_or(&UNKNOWN, |model| model.capabilities)
+    }
+
+    /// Refuses `command` if the detected model doesn't have it. Allowed
+    /// until a model has been detected.
+    pub(super) fn check_supported(&self, command: &str) -> Result<(), ScannerError> {
+        match &self.model {
+            Some(model) if !model.capabilities.supports(command) => Err(ScannerError::Unsupported {
+                command: command.to_string(),
+                model: model.name.clone(),
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::transport::MemoryTransport;
+    use crate::simulator::Simulator;
+
+    #[test]
+    fn test_capabilities() {
+        let model = ScannerModel::new("UBC125XLT", "Version 1.06.06");
+        assert!(model.is_known());
+        assert_eq!(model.capabilities.channel_count, 500);
+        assert!(model.capabilities.supports("GLG"));
+        assert!(model.capabilities.supports("CIN"));
+
+        let model = ScannerModel::new("BC345CRS", "Version 1.00");
+        assert!(!model.is_known());
+        assert!(!model.capabilities.supports("STS"));
+        assert!(model.capabilities.supports("VOL"));
+        assert_eq!(model.capabilities.bank_count, 10);
+        assert_eq!(model.capabilities.bank_channels(2), 51..=100);
+        assert_eq!(model.capabilities.bank_of(500), 10);
+
+        let model = ScannerModel::new("UBC126AT", "Version 1.01.03");
+        assert!(model.is_known());
+        assert_eq!(model.capabilities.channel_count, 500);
+        assert!(model.capabilities.check_frequency("88.5".parse().unwrap()).is_ok());
+        assert!(BC125AT.check_frequency("88.5".parse().unwrap()).is_err());
+
+        let steps: Vec<String> = LAND_MOBILE.iter().chain(AIRBAND).map(Step::to_string).collect();
+        assert_eq!(steps, ["5 kHz", "6.25 kHz", "7.5 kHz", "12.5 kHz", "25 kHz", "8.33 kHz"]);
+    }
+
+    #[test]
+    fn test_frequency_checks() {
+        let check = |mhz: &str| BC125AT.check_frequency(mhz.parse().unwrap());
+        for ok in ["25", "145.5", "118.0083", "118.0167", "118.025", "462.5625", "162.55", "512"] {
+            assert!(check(ok).is_ok(), "{}", ok);
+        }
+        // Between 8.33 kHz channels, and off every other step
+        assert!(check("118.012").is_err());
+        assert!(check("145.5013").is_err());
+        // 8.33 kHz spacing is only used on airband
+        let Err(ScannerError::InvalidArgument(msg)) = check("145.0083") else { panic!() };
+        assert_eq!(msg, "145.0083 MHz is not on a channel step (5 kHz, 6.25 kHz, 7.5 kHz, 12.5 kHz)");
+        assert!(check("136.9917").is_ok());
+        assert!(check("137.0125").is_ok());
+
+        let Err(ScannerError::InvalidArgument(msg)) = check("1234.5678") else { panic!() };
+        assert_eq!(
+            msg,
+            "1234.5678 MHz is outside the scanner's bands (25-54, 108-174, 225-380, 400-512 MHz)"
+        );
+        assert!(check("88.5").is_err());
+        assert!(check("0").is_err());
+        assert!(BC125AT.check_band("380.0013".parse().unwrap()).is_err());
+        assert!(BC125AT.check_band("145.5013".parse().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn test_unsupported_commands_are_refused() {
+        let mut sim = Simulator::new();
+        let mut client = ScannerClient::new(Box::new(MemoryTransport::new(move |cmd| {
+            match cmd {
+                "MDL" => Some("MDL,BC345CRS".to_string()),
+                _ => Some(sim.handle(cmd)),
+            }
+        })));
+
+        // Nothing is refused before the model is known
+        assert!(client.send_command("STS").is_ok());
+        let model = client.detect_model().unwrap();
+        assert_eq!(model.to_string(), "BC345CRS (Version 1.06.06)");
+        assert!(matches!(
+            client.send_command("GLG"),
+            Err(ScannerError::Unsupported { ref command, .. }) if command == "GLG"
+        ));
+        assert!(client.display_status().is_err());
+        assert!(client.get_volume().is_ok());
+    }
+}
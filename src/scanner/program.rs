@@ -0,0 +1,212 @@
+use super::{Frequency, RadioMode, ScannerClient, ScannerError, unexpected};
+use super::channel::ChannelInfo;
+use super::keys::{Key, KeyAction};
+
+
+/// More global lockouts than the radio can hold, so a `GLF` walk that never
+/// reaches `-1` gives up.
+const MAX_LOCKOUTS: usize = 1000;
+
+/// Commands the scanner only accepts in program mode. They are only
+/// reachable through a [`ProgramSession`].
+pub(super) const PROGRAM_COMMANDS: &[&str] = &[
+    "PRG", "EPG", "CIN", "SCG", "DCH", "CLR", "BLT", "BSV", "KBP", "PRI", "CNT", "WXS", "GLF",
+    "LOF", "ULF", "CSP", "CSG", "CLC", "SSG",
+];
+
+/// The scanner in program mode ("Remote Mode" on its display).
+///
+/// Created by [`ScannerClient::program`], which sends `PRG`. Dropping the
+/// session always sends `EPG`, even if a command failed part way, so the
+/// radio is never left stuck in program mode. After `EPG` the radio holds;
+/// use [`ProgramSession::resume_scan`] to have it scan again.
+pub struct ProgramSession<'a> {
+    pub(super) client: &'a mut ScannerClient,
+    resume_scan: bool,
+}
+
+impl<'a> ProgramSession<'a> {
+    pub(super) fn enter(client: &'a mut ScannerClient) -> Result<Self, ScannerError> {
+        client.send_set("PRG")?;
+        client.mode = RadioMode::Program { resume_scan: false };
+        Ok(Self {
+            client,
+            resume_scan: false,
+        })
+    }
+
+    /// Resumes scanning (`KEY,S,P`) after leaving program mode.
+    pub fn resume_scan(mut self) -> Self {
+        self.resume_scan = true;
+        self.client.mode = RadioMode::Program { resume_scan: true };
+        self
+    }
+
+    fn check_index(&self, index: u32) -> Result<(), ScannerError> {
+        let count = u32::from(self.client.capabilities().channel_count);
+        if !(1..=count).contains(&index) {
+            return Err(ScannerError::InvalidArgument(format!(
+                "Channel index must be between 1 and {}",
+                count
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn get_channel(&mut self, index: u32) -> Result<ChannelInfo, ScannerError> {
+        self.check_index(index)?;
+        let cmd = format!("CIN,{}", index);
+        let response = self.client.command(&cmd)?;
+        let channel = ChannelInfo::parse(&response)?;
+        if channel.index != index {
+            return Err(unexpected(&cmd, response));
+        }
+        Ok(channel)
+    }
+
+    /// Writes every CIN field of `channel`, so reading it back gives the
+    /// same value.
+    pub fn set_channel(&mut self, channel: &ChannelInfo) -> Result<(), ScannerError> {
+        self.check_index(channel.index)?;
+        self.client.capabilities().check_frequency(channel.frequency)?;
+        self.client.send_set(&channel.to_command()?)
+    }
+
+    /// Reads several channels, calling `progress(done, total)` after each.
+    pub fn get_channels(
+        &mut self,
+        indices: &[u32],
+        mut progress: impl FnMut(usize, usize),
+    ) -> Result<Vec<ChannelInfo>, ScannerError> {
+        let mut channels = Vec::with_capacity(indices.len());
+        for &index in indices {
+            channels.push(self.get_channel(index)?);
+            progress(channels.len(), indices.len());
+        }
+        Ok(channels)
+    }
+
+    pub fn delete_channel(&mut self, index: u32) -> Result<(), ScannerError> {
+        self.check_index(index)?;
+        self.client.send_set(&format!("DCH,{}", index))
+    }
+
+    /// Resets every channel and setting to the factory defaults. This takes
+    /// a while, see [`ScannerClient::timeout_for`].
+    #[allow(dead_code)]
+    pub fn clear_memory(&mut self) -> Result<(), ScannerError> {
+        self.client.send_set("CLR")
+    }
+
+    /// Reads a mask such as `SCG,0111111111`, where `0` means included.
+    pub(super) fn get_mask<const N: usize>(&mut self, cmd: &str) -> Result<[bool; N], ScannerError> {
+        let mut included = [false; N];
+        included.copy_from_slice(&self.read_mask(cmd, N)?);
+        Ok(included)
+    }
+
+    /// [`Self::get_mask`] for a mask whose length depends on the model.
+    fn read_mask(&mut self, cmd: &str, len: usize) -> Result<Vec<bool>, ScannerError> {
+        let fields = self.client.query(cmd)?;
+        match fields.as_slice() {
+            [mask] if mask.len() == len && mask.chars().all(|c| c == '0' || c == '1') => {
+                Ok(mask.chars().map(|c| c == '0').collect())
+            }
+            _ => Err(unexpected(cmd, format!("{},{}", cmd, fields.join(",")))),
+        }
+    }
+
+    /// Writes a mask read by [`Self::get_mask`]. The radio refuses to
+    /// exclude everything, so that is an error here too.
+    pub(super) fn set_mask(&mut self, cmd: &str, included: &[bool], what: &str) -> Result<(), ScannerError> {
+        if !included.contains(&true) {
+            return Err(ScannerError::InvalidArgument(format!(
+                "At least one {} must be enabled",
+                what
+            )));
+        }
+        let mask: String = included.iter().map(|&on| if on { '0' } else { '1' }).collect();
+        self.client.send_set(&format!("{},{}", cmd, mask))
+    }
+
+    /// Which banks are included in the scan, bank 1 first; one per bank
+    /// of the model.
+    pub fn get_scan_banks(&mut self) -> Result<Vec<bool>, ScannerError> {
+        let count = self.client.capabilities().bank_count;
+        self.read_mask("SCG", count)
+    }
+
+    pub fn set_scan_banks(&mut self, banks: &[bool]) -> Result<(), ScannerError> {
+        let count = self.client.capabilities().bank_count;
+        if banks.len() != count {
+            return Err(ScannerError::InvalidArgument(format!(
+                "Expected {} banks, got {}",
+                count,
+                banks.len()
+            )));
+        }
+        self.set_mask("SCG", banks, "bank")
+    }
+
+    /// The global lockout list, calling `progress(found)` after each entry.
+    ///
+    /// Each `GLF` returns the next entry, and `-1` after the last, so this
+    /// must start from a fresh session to see the whole list.
+    pub fn get_lockouts(
+        &mut self,
+        mut progress: impl FnMut(usize),
+    ) -> Result<Vec<Frequency>, ScannerError> {
+        let mut lockouts = Vec::new();
+        while lockouts.len() < MAX_LOCKOUTS {
+            let fields = self.client.query("GLF")?;
+            let bad = || unexpected("GLF", format!("GLF,{}", fields.join(",")));
+            match fields.as_slice() {
+                [end] if end == "-1" => return Ok(lockouts),
+                [frequency] => {
+                    lockouts.push(Frequency::from_scanner(frequency).map_err(|_| bad())?);
+                    progress(lockouts.len());
+                }
+                _ => return Err(bad()),
+            }
+        }
+        Err(unexpected("GLF", format!("more than {} lockouts", MAX_LOCKOUTS)))
+    }
+
+    /// Adds `frequency` to the global lockout list, so scans and searches
+    /// skip it.
+    pub fn lock_out(&mut self, frequency: Frequency) -> Result<(), ScannerError> {
+        Self::check_lockout(frequency)?;
+        self.client.send_set(&format!("LOF,{}", frequency.to_scanner()))
+    }
+
+    /// Removes `frequency` from the global lockout list.
+    pub fn unlock(&mut self, frequency: Frequency) -> Result<(), ScannerError> {
+        Self::check_lockout(frequency)?;
+        self.client.send_set(&format!("ULF,{}", frequency.to_scanner()))
+    }
+
+    fn check_lockout(frequency: Frequency) -> Result<(), ScannerError> {
+        if frequency.is_zero() {
+            return Err(ScannerError::InvalidArgument(
+                "No frequency to lock out".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for ProgramSession<'_> {
+    fn drop(&mut self) {
+        if let Err(e) = self.client.send_set("EPG") {
+            // Left to ScannerClient::reconnect if the link has gone
+            tracing::warn!("Failed to leave program mode: {}", e);
+            return;
+        }
+        self.client.mode = RadioMode::Holding(None);
+        if self.resume_scan
+            && let Err(e) = self.client.press(Key::Scan, KeyAction::Press)
+        {
+            tracing::warn!("Failed to resume scanning: {}", e);
+        }
+    }
+}
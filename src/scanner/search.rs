@@ -0,0 +1,308 @@
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use super::{Frequency, ProgramSession, ScannerClient, ScannerError, unexpected};
+use super::keys::{Key, KeyAction};
+use super::model::Capabilities;
+
+pub const SEARCH_RANGE_COUNT: usize = 10;
+
+/// The radio's built-in service searches, in `SSG` order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServiceGroup {
+    Police,
+    FireEmergency,
+    Ham,
+    Marine,
+    Railroad,
+    Air,
+    Cb,
+    FrsGmrs,
+    Racing,
+    Special,
+}
+
+impl ServiceGroup {
+    pub const ALL: [ServiceGroup; 10] = [
+        ServiceGroup::Police,
+        ServiceGroup::FireEmergency,
+        ServiceGroup::Ham,
+        ServiceGroup::Marine,
+        ServiceGroup::Railroad,
+        ServiceGroup::Air,
+        ServiceGroup::Cb,
+        ServiceGroup::FrsGmrs,
+        ServiceGroup::Racing,
+        ServiceGroup::Special,
+    ];
+}
+
+impl fmt::Display for ServiceGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ServiceGroup::Police => "Police",
+            ServiceGroup::FireEmergency => "Fire/Emergency",
+            ServiceGroup::Ham => "HAM",
+            ServiceGroup::Marine => "Marine",
+            ServiceGroup::Railroad => "Railroad",
+            ServiceGroup::Air => "Air",
+            ServiceGroup::Cb => "CB",
+            ServiceGroup::FrsGmrs => "FRS/GMRS",
+            ServiceGroup::Racing => "Racing",
+            ServiceGroup::Special => "Special",
+        })
+    }
+}
+
+/// Which service searches are enabled, from the `SSG` mask.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ServiceGroups([bool; ServiceGroup::ALL.len()]);
+
+impl ServiceGroups {
+    pub fn contains(&self, group: ServiceGroup) -> bool {
+        self.0[group as usize]
+    }
+
+    pub fn set(&mut self, group: ServiceGroup, enabled: bool) {
+        self.0[group as usize] = enabled;
+    }
+
+    /// Every group with whether it is enabled, in `SSG` order.
+    pub fn iter(&self) -> impl Iterator<Item = (ServiceGroup, bool)> + '_ {
+        ServiceGroup::ALL.into_iter().zip(self.0)
+    }
+}
+
+impl FromIterator<ServiceGroup> for ServiceGroups {
+    fn from_iter<I: IntoIterator<Item = ServiceGroup>>(groups: I) -> Self {
+        let mut enabled = ServiceGroups([false; ServiceGroup::ALL.len()]);
+        for group in groups {
+            enabled.set(group, true);
+        }
+        enabled
+    }
+}
+
+/// One of the radio's custom search ranges, as read and written with `CSP`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchRange {
+    /// 1-10.
+    pub index: u32,
+    pub lower: Frequency,
+    pub upper: Frequency,
+}
+
+impl SearchRange {
+    /// Parses a `CSP,[INDEX],[LIMIT_L],[LIMIT_H]` reply.
+    pub fn parse(line: &str) -> Result<Self, ScannerError> {
+        let bad = |what: &str| ScannerError::UnexpectedResponse {
+            sent: "CSP".to_string(),
+            got: format!("{} ({})", line, what),
+        };
+        let parts: Vec<&str> = line.split(',').collect();
+        let ["CSP", index, lower, upper] = parts.as_slice() else {
+            return Err(bad("expected 4 fields"));
+        };
+        Ok(Self {
+            index: index.parse().map_err(|_| bad("index"))?,
+            lower: Frequency::from_scanner(lower).map_err(|_| bad("lower limit"))?,
+            upper: Frequency::from_scanner(upper).map_err(|_| bad("upper limit"))?,
+        })
+    }
+
+    /// The `CSP` command that writes this range. Fails if the limits are
+    /// missing or the wrong way round.
+    pub fn to_command(self) -> Result<String, ScannerError> {
+        check_index(self.index)?;
+        if self.lower.is_zero() || self.upper.is_zero() {
+            return Err(ScannerError::InvalidArgument(
+                "A search range needs both limits".to_string(),
+            ));
+        }
+        if self.lower > self.upper {
+            return Err(ScannerError::InvalidArgument(format!(
+                "Lower limit {} is above upper limit {}",
+                self.lower, self.upper
+            )));
+        }
+        Ok(format!(
+            "CSP,{},{},{}",
+            self.index,
+            self.lower.to_scanner(),
+            self.upper.to_scanner()
+        ))
+    }
+
+    /// Checks that both limits are frequencies the radio can receive. The
+    /// range itself may span the gaps between bands.
+    pub fn check_bands(self, capabilities: &Capabilities) -> Result<(), ScannerError> {
+        capabilities.check_band(self.lower)?;
+        capabilities.check_band(self.upper)
+    }
+}
+
+fn check_index(index: u32) -> Result<(), ScannerError> {
+    if !(1..=SEARCH_RANGE_COUNT as u32).contains(&index) {
+        return Err(ScannerError::InvalidArgument(format!(
+            "Search range must be between 1 and {}",
+            SEARCH_RANGE_COUNT
+        )));
+    }
+    Ok(())
+}
+
+impl ProgramSession<'_> {
+    pub fn get_search_range(&mut self, index: u32) -> Result<SearchRange, ScannerError> {
+        check_index(index)?;
+        let cmd = format!("CSP,{}", index);
+        let response = self.client.command(&cmd)?;
+        let range = SearchRange::parse(&response)?;
+        if range.index != index {
+            return Err(unexpected(&cmd, response));
+        }
+        Ok(range)
+    }
+
+    /// All ten ranges, range 1 first.
+    pub fn get_search_ranges(&mut self) -> Result<Vec<SearchRange>, ScannerError> {
+        (1..=SEARCH_RANGE_COUNT as u32)
+            .map(|index| self.get_search_range(index))
+            .collect()
+    }
+
+    pub fn set_search_range(&mut self, range: &SearchRange) -> Result<(), ScannerError> {
+        range.check_bands(self.client.capabilities())?;
+        self.client.send_set(&range.to_command()?)
+    }
+
+    /// Which ranges a custom search covers (`CSG`), range 1 first.
+    pub fn get_search_groups(&mut self) -> Result<[bool; SEARCH_RANGE_COUNT], ScannerError> {
+        self.get_mask("CSG")
+    }
+
+    pub fn set_search_groups(
+        &mut self,
+        enabled: &[bool; SEARCH_RANGE_COUNT],
+    ) -> Result<(), ScannerError> {
+        self.set_mask("CSG", enabled, "search range")
+    }
+
+    pub fn get_service_groups(&mut self) -> Result<ServiceGroups, ScannerError> {
+        self.get_mask("SSG").map(ServiceGroups)
+    }
+
+    pub fn set_service_groups(&mut self, groups: &ServiceGroups) -> Result<(), ScannerError> {
+        self.set_mask("SSG", &groups.0, "service group")
+    }
+}
+
+impl ScannerClient {
+    /// Starts searching the enabled custom search ranges. Pressing Scan
+    /// first leaves whatever mode the radio was in, so Search always starts
+    /// a custom search rather than resuming another kind.
+    pub fn start_custom_search(&mut self) -> Result<(), ScannerError> {
+        self.press_keys(&[(Key::Scan, KeyAction::Press), (Key::Search, KeyAction::Press)])
+    }
+
+    /// Starts searching the enabled service groups, with Func then Search.
+    pub fn start_service_search(&mut self) -> Result<(), ScannerError> {
+        self.press_keys(&[
+            (Key::Scan, KeyAction::Press),
+            (Key::Func, KeyAction::Press),
+            (Key::Search, KeyAction::Press),
+        ])
+    }
+
+    /// Reads the service groups in a program mode session of its own, then
+    /// resumes scanning.
+    pub fn get_service_groups(&mut self) -> Result<ServiceGroups, ScannerError> {
+        self.program()?.resume_scan().get_service_groups()
+    }
+
+    /// Writes the service groups in a program mode session of its own, then
+    /// resumes scanning.
+    #[allow(dead_code)]
+    pub fn set_service_groups(&mut self, groups: &ServiceGroups) -> Result<(), ScannerError> {
+        self.program()?.resume_scan().set_service_groups(groups)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use crate::scanner::transport::MemoryTransport;
+    use crate::simulator::Simulator;
+
+    #[test]
+    fn test_csp_round_trip() {
+        let line = "CSP,5,01080000,01369916";
+        let range = SearchRange::parse(line).unwrap();
+        assert_eq!(range.lower.to_string(), "108.0000");
+        assert_eq!(range.to_command().unwrap(), line);
+
+        assert!(SearchRange::parse("CSP,5,01080000").is_err());
+        assert!(SearchRange { index: 11, ..range }.to_command().is_err());
+        assert!(SearchRange { lower: range.upper, upper: range.lower, ..range }.to_command().is_err());
+        assert!(SearchRange { lower: Frequency::default(), ..range }.to_command().is_err());
+    }
+
+    #[test]
+    fn test_search_ranges_and_groups() {
+        let mut sim = Simulator::new();
+        let mut client = ScannerClient::new(Box::new(MemoryTransport::new(move |cmd| {
+            Some(sim.handle(cmd))
+        })));
+
+        let airband = SearchRange {
+            index: 3,
+            lower: "118".parse().unwrap(),
+            upper: "137".parse().unwrap(),
+        };
+        let mut groups = [false; SEARCH_RANGE_COUNT];
+        groups[2] = true;
+        {
+            let mut session = client.program().unwrap();
+            assert_eq!(session.get_search_ranges().unwrap().len(), SEARCH_RANGE_COUNT);
+            session.set_search_range(&airband).unwrap();
+            session.set_search_groups(&groups).unwrap();
+            assert!(matches!(
+                session.set_search_groups(&[false; SEARCH_RANGE_COUNT]),
+                Err(ScannerError::InvalidArgument(_))
+            ));
+        }
+        let mut session = client.program().unwrap();
+        assert_eq!(session.get_search_range(3).unwrap(), airband);
+        assert_eq!(session.get_search_groups().unwrap(), groups);
+        assert!(session.get_search_range(0).is_err());
+    }
+
+    #[test]
+    fn test_service_groups() {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let log = sent.clone();
+        let mut sim = Simulator::new();
+        let mut client = ScannerClient::new(Box::new(MemoryTransport::new(move |cmd| {
+            log.lock().unwrap().push(cmd.to_string());
+            Some(sim.handle(cmd))
+        })));
+
+        let mut groups = client.get_service_groups().unwrap();
+        assert!(groups.iter().all(|(_, on)| on));
+        groups.set(ServiceGroup::Ham, false);
+        groups.set(ServiceGroup::Racing, false);
+        client.set_service_groups(&groups).unwrap();
+        assert!(sent.lock().unwrap().contains(&"SSG,0010000010".to_string()));
+        let groups = client.get_service_groups().unwrap();
+        assert!(!groups.contains(ServiceGroup::Ham) && groups.contains(ServiceGroup::Air));
+
+        let none: ServiceGroups = std::iter::empty().collect();
+        assert!(matches!(
+            client.set_service_groups(&none),
+            Err(ScannerError::InvalidArgument(_))
+        ));
+        let marine: ServiceGroups = [ServiceGroup::Marine].into_iter().collect();
+        client.set_service_groups(&marine).unwrap();
+        assert_eq!(client.get_service_groups().unwrap(), marine);
+    }
+}
@@ -0,0 +1,303 @@
+use std::ops::RangeInclusive;
+use serde::{Deserialize, Serialize};
+use super::{ProgramSession, ScannerError, unexpected};
+
+/// When the display backlight comes on (`BLT`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Backlight {
+    AlwaysOn,
+    AlwaysOff,
+    Keypress,
+    /// On a keypress or when the squelch opens.
+    KeySquelch,
+}
+
+impl Backlight {
+    fn code(self) -> &'static str {
+        match self {
+            Backlight::AlwaysOn => "AO",
+            Backlight::AlwaysOff => "AF",
+            Backlight::Keypress => "KY",
+            Backlight::KeySquelch => "KS",
+        }
+    }
+
+    fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "AO" => Some(Backlight::AlwaysOn),
+            "AF" => Some(Backlight::AlwaysOff),
+            "KY" => Some(Backlight::Keypress),
+            "KS" => Some(Backlight::KeySquelch),
+            _ => None,
+        }
+    }
+}
+
+/// The key beep (`KBP` level).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BeepLevel {
+    /// Beeps at a level following the volume.
+    Auto,
+    Off,
+}
+
+/// How the priority channels are checked while scanning (`PRI`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PriorityMode {
+    Off,
+    On,
+    /// Scans only the priority channels.
+    PlusOn,
+    /// Checks priority channels without interrupting a transmission.
+    DoNotDisturb,
+}
+
+impl PriorityMode {
+    const ALL: [PriorityMode; 4] = [
+        PriorityMode::Off,
+        PriorityMode::On,
+        PriorityMode::PlusOn,
+        PriorityMode::DoNotDisturb,
+    ];
+}
+
+pub const CHARGE_TIME_HOURS: RangeInclusive<u8> = 1..=16;
+pub const CONTRAST_LEVELS: RangeInclusive<u8> = 1..=15;
+
+/// The radio's general settings, read and written together in one program
+/// mode session with [`ProgramSession::get_settings`] and
+/// [`ProgramSession::set_settings`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScannerSettings {
+    pub backlight: Backlight,
+    /// Battery charge time, in hours.
+    pub charge_time: u8,
+    pub beep: BeepLevel,
+    pub key_lock: bool,
+    pub priority: PriorityMode,
+    pub contrast: u8,
+    /// Weather alerts take priority over scanning.
+    pub weather_priority: bool,
+}
+
+impl ScannerSettings {
+    /// Checks every field, so nothing is written unless all of it can be.
+    pub fn validate(&self) -> Result<(), ScannerError> {
+        check_charge_time(self.charge_time)?;
+        check_contrast(self.contrast)
+    }
+}
+
+fn check_charge_time(hours: u8) -> Result<(), ScannerError> {
+    if !CHARGE_TIME_HOURS.contains(&hours) {
+        return Err(ScannerError::InvalidArgument(
+            "Charge time must be between 1 and 16 hours".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn check_contrast(level: u8) -> Result<(), ScannerError> {
+    if !CONTRAST_LEVELS.contains(&level) {
+        return Err(ScannerError::InvalidArgument(
+            "Contrast must be between 1 and 15".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// The single field of a settings reply, e.g. `KY` from `BLT,KY`.
+fn single(cmd: &str, fields: Vec<String>) -> Result<String, ScannerError> {
+    match <[String; 1]>::try_from(fields) {
+        Ok([field]) => Ok(field),
+        Err(fields) => Err(unexpected(cmd, format!("{},{}", cmd, fields.join(",")))),
+    }
+}
+
+fn flag(cmd: &str, field: &str) -> Result<bool, ScannerError> {
+    match field {
+        "0" => Ok(false),
+        "1" => Ok(true),
+        _ => Err(unexpected(cmd, format!("{},{}", cmd, field))),
+    }
+}
+
+impl ProgramSession<'_> {
+    pub fn get_settings(&mut self) -> Result<ScannerSettings, ScannerError> {
+        let (beep, key_lock) = self.get_key_beep()?;
+        Ok(ScannerSettings {
+            backlight: self.get_backlight()?,
+            charge_time: self.get_charge_time()?,
+            beep,
+            key_lock,
+            priority: self.get_priority_mode()?,
+            contrast: self.get_contrast()?,
+            weather_priority: self.get_weather_priority()?,
+        })
+    }
+
+    pub fn set_settings(&mut self, settings: &ScannerSettings) -> Result<(), ScannerError> {
+        settings.validate()?;
+        self.set_backlight(settings.backlight)?;
+        self.set_charge_time(settings.charge_time)?;
+        self.set_key_beep(settings.beep, settings.key_lock)?;
+        self.set_priority_mode(settings.priority)?;
+        self.set_contrast(settings.contrast)?;
+        self.set_weather_priority(settings.weather_priority)
+    }
+
+    pub fn get_backlight(&mut self) -> Result<Backlight, ScannerError> {
+        let code = single("BLT", self.client.query("BLT")?)?;
+        Backlight::from_code(&code).ok_or_else(|| unexpected("BLT", format!("BLT,{}", code)))
+    }
+
+    pub fn set_backlight(&mut self, backlight: Backlight) -> Result<(), ScannerError> {
+        self.client.send_set(&format!("BLT,{}", backlight.code()))
+    }
+
+    pub fn get_charge_time(&mut self) -> Result<u8, ScannerError> {
+        let hours = single("BSV", self.client.query("BSV")?)?;
+        hours
+            .parse()
+            .ok()
+            .filter(|h| CHARGE_TIME_HOURS.contains(h))
+            .ok_or_else(|| unexpected("BSV", format!("BSV,{}", hours)))
+    }
+
+    pub fn set_charge_time(&mut self, hours: u8) -> Result<(), ScannerError> {
+        check_charge_time(hours)?;
+        self.client.send_set(&format!("BSV,{}", hours))
+    }
+
+    /// The key beep and whether the keypad is locked.
+    pub fn get_key_beep(&mut self) -> Result<(BeepLevel, bool), ScannerError> {
+        let fields = self.client.query("KBP")?;
+        let beep = match fields.first().map(String::as_str) {
+            Some("0") => BeepLevel::Auto,
+            Some("99") => BeepLevel::Off,
+            _ => return Err(unexpected("KBP", format!("KBP,{}", fields.join(",")))),
+        };
+        match fields.get(1) {
+            Some(lock) if fields.len() == 2 => Ok((beep, flag("KBP", lock)?)),
+            _ => Err(unexpected("KBP", format!("KBP,{}", fields.join(",")))),
+        }
+    }
+
+    pub fn set_key_beep(&mut self, beep: BeepLevel, key_lock: bool) -> Result<(), ScannerError> {
+        let level = match beep {
+            BeepLevel::Auto => 0,
+            BeepLevel::Off => 99,
+        };
+        self.client.send_set(&format!("KBP,{},{}", level, key_lock as u8))
+    }
+
+    pub fn get_priority_mode(&mut self) -> Result<PriorityMode, ScannerError> {
+        let mode = single("PRI", self.client.query("PRI")?)?;
+        mode.parse::<usize>()
+            .ok()
+            .and_then(|m| PriorityMode::ALL.get(m).copied())
+            .ok_or_else(|| unexpected("PRI", format!("PRI,{}", mode)))
+    }
+
+    pub fn set_priority_mode(&mut self, mode: PriorityMode) -> Result<(), ScannerError> {
+        self.client.send_set(&format!("PRI,{}", mode as u8))
+    }
+
+    pub fn get_contrast(&mut self) -> Result<u8, ScannerError> {
+        let level = single("CNT", self.client.query("CNT")?)?;
+        level
+            .parse()
+            .ok()
+            .filter(|l| CONTRAST_LEVELS.contains(l))
+            .ok_or_else(|| unexpected("CNT", format!("CNT,{}", level)))
+    }
+
+    pub fn set_contrast(&mut self, level: u8) -> Result<(), ScannerError> {
+        check_contrast(level)?;
+        self.client.send_set(&format!("CNT,{}", level))
+    }
+
+    pub fn get_weather_priority(&mut self) -> Result<bool, ScannerError> {
+        let priority = single("WXS", self.client.query("WXS")?)?;
+        flag("WXS", &priority)
+    }
+
+    pub fn set_weather_priority(&mut self, on: bool) -> Result<(), ScannerError> {
+        self.client.send_set(&format!("WXS,{}", on as u8))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use crate::scanner::ScannerClient;
+    use crate::scanner::transport::MemoryTransport;
+    use crate::simulator::Simulator;
+
+    #[test]
+    fn test_settings_round_trip_in_one_session() {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let log = sent.clone();
+        let mut sim = Simulator::new();
+        let mut client = ScannerClient::new(Box::new(MemoryTransport::new(move |cmd| {
+            log.lock().unwrap().push(cmd.to_string());
+            Some(sim.handle(cmd))
+        })));
+
+        assert!(matches!(client.send_command("BLT"), Err(ScannerError::InvalidArgument(_))));
+
+        let mut settings = client.program().unwrap().get_settings().unwrap();
+        settings.backlight = Backlight::KeySquelch;
+        settings.charge_time = 16;
+        settings.beep = BeepLevel::Off;
+        settings.key_lock = true;
+        settings.priority = PriorityMode::DoNotDisturb;
+        settings.contrast = 1;
+        settings.weather_priority = true;
+        {
+            let mut session = client.program().unwrap();
+            session.set_settings(&settings).unwrap();
+            assert_eq!(session.get_settings().unwrap(), settings);
+        }
+        let sent = sent.lock().unwrap();
+        assert_eq!(sent.iter().filter(|c| *c == "PRG").count(), 2);
+        assert_eq!(sent.last().unwrap(), "EPG");
+        assert!(sent.contains(&"KBP,99,1".to_string()));
+        assert!(sent.contains(&"BLT,KS".to_string()));
+    }
+
+    #[test]
+    fn test_settings_range_checks() {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let log = sent.clone();
+        let mut sim = Simulator::new();
+        let mut client = ScannerClient::new(Box::new(MemoryTransport::new(move |cmd| {
+            log.lock().unwrap().push(cmd.to_string());
+            Some(sim.handle(cmd))
+        })));
+        let settings = ScannerSettings {
+            backlight: Backlight::AlwaysOn,
+            charge_time: 17,
+            beep: BeepLevel::Auto,
+            key_lock: false,
+            priority: PriorityMode::Off,
+            contrast: 8,
+            weather_priority: false,
+        };
+        let invalid = |r: Result<(), ScannerError>| matches!(r, Err(ScannerError::InvalidArgument(_)));
+        {
+            let mut session = client.program().unwrap();
+            // Nothing is written when any field is out of range
+            assert!(invalid(session.set_settings(&settings)));
+            assert!(invalid(session.set_charge_time(0)));
+            assert!(invalid(session.set_contrast(0)));
+            assert!(invalid(session.set_contrast(16)));
+        }
+        assert_eq!(*sent.lock().unwrap(), vec!["PRG", "EPG"]);
+        assert!(ScannerSettings { charge_time: 16, contrast: 15, ..settings }.validate().is_ok());
+    }
+}
@@ -0,0 +1,116 @@
+use super::{Frequency, ScannerError};
+use super::channel::Modulation;
+use super::model::Capabilities;
+
+/// What the scanner is receiving, from a `GLG` reply:
+/// `GLG,[FRQ],[MOD],[ATT],[SIGNAL],,,[NAME],[SQL],[MUT],,[CHANNEL],`.
+///
+/// See the GLG row of SCANNER-COMMANDS.md; the attenuator is the slot left
+/// blank there. The other blank slots are always empty on this radio.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScanStatus {
+    pub frequency: Frequency,
+    pub modulation: Modulation,
+    pub attenuator: bool,
+    /// The documented Signal Status field. What its values mean isn't
+    /// documented; it reads 0 in every reply seen so far.
+    pub signal_status: u8,
+    pub channel_name: String,
+    /// Squelch open, i.e. a signal is being received.
+    pub squelch_open: bool,
+    pub muted: bool,
+    /// The memory channel, `None` for a search or Close Call hit.
+    pub channel: Option<u32>,
+}
+
+impl ScanStatus {
+    /// Parses a `GLG` reply. The all-blank reply the scanner sends while it
+    /// is moving between channels is `None`.
+    pub fn parse(line: &str) -> Result<Option<Self>, ScannerError> {
+        let bad = |what: &str| ScannerError::UnexpectedResponse {
+            sent: "GLG".to_string(),
+            got: format!("{} ({})", line, what),
+        };
+        let flag = |s: &str, what: &str| match s {
+            "" | "0" => Ok(false),
+            "1" => Ok(true),
+            _ => Err(bad(what)),
+        };
+
+        let parts: Vec<&str> = line.split(',').collect();
+        if parts.len() < 12 || parts[0] != "GLG" {
+            return Err(bad("expected 12 fields"));
+        }
+        if parts[1..].iter().all(|p| p.trim().is_empty()) {
+            return Ok(None);
+        }
+
+        let channel = match parts[11].trim() {
+            "" => None,
+            index => Some(index.parse().map_err(|_| bad("channel"))?),
+        };
+        Ok(Some(Self {
+            frequency: Frequency::from_scanner(parts[1]).map_err(|_| bad("frequency"))?,
+            modulation: parts[2].parse().map_err(|_| bad("modulation"))?,
+            attenuator: flag(parts[3], "attenuator")?,
+            signal_status: match parts[4].trim() {
+                "" => 0,
+                status => status.parse().map_err(|_| bad("signal status"))?,
+            },
+            channel_name: parts[7].trim().to_string(),
+            squelch_open: flag(parts[8], "squelch")?,
+            muted: flag(parts[9], "mute")?,
+            channel,
+        }))
+    }
+
+    /// The bank the channel is in.
+    pub fn bank(&self, capabilities: &Capabilities) -> Option<u32> {
+        self.channel
+            .filter(|&index| index > 0)
+            .map(|index| capabilities.bank_of(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::model;
+
+    #[test]
+    fn test_parse_glg() {
+        let status = ScanStatus::parse("GLG,01239750,AM,,0,,,BHX RADAR,1,0,,52,")
+            .unwrap()
+            .unwrap();
+        assert_eq!(status.frequency.to_string(), "123.9750");
+        assert_eq!(status.modulation, Modulation::Am);
+        assert!(!status.attenuator);
+        assert_eq!(status.signal_status, 0);
+        assert_eq!(status.channel_name, "BHX RADAR");
+        assert!(status.squelch_open && !status.muted);
+        assert_eq!(status.channel, Some(52));
+        assert_eq!(status.bank(&model::UNKNOWN), Some(2));
+
+        let status = ScanStatus::parse("GLG,01456250,NFM,1,3,,,REPEATER,0,1,,500,")
+            .unwrap()
+            .unwrap();
+        assert!(status.attenuator && status.muted && !status.squelch_open);
+        assert_eq!(status.signal_status, 3);
+        assert_eq!(status.bank(&model::UNKNOWN), Some(10));
+
+        // A search hit has no channel
+        let status = ScanStatus::parse("GLG,01563000,FM,,0,,,,1,0,,,").unwrap().unwrap();
+        assert_eq!(status.channel, None);
+        assert_eq!(status.bank(&model::UNKNOWN), None);
+    }
+
+    #[test]
+    fn test_parse_glg_blank_and_errors() {
+        assert_eq!(ScanStatus::parse("GLG,,,,,,,,,,,,").unwrap(), None);
+        assert!(ScanStatus::parse("GLG,01239750,AM,,0,,,BHX RADAR").is_err());
+        assert!(ScanStatus::parse("GLG,01239750,USB,,0,,,BHX RADAR,1,0,,52,").is_err());
+        assert!(ScanStatus::parse("GLG,01239750,AM,,S5,,,BHX RADAR,1,0,,52,").is_err());
+        assert!(ScanStatus::parse("GLG,01239750,AM,,0,,,BHX RADAR,2,0,,52,").is_err());
+        assert!(ScanStatus::parse("STS,01239750,AM,,0,,,BHX RADAR,1,0,,52,").is_err());
+    }
+}
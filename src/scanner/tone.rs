@@ -0,0 +1,194 @@
+use std::fmt;
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+use super::ScannerError;
+
+/// CTCSS tones in tenths of Hz, for codes 64-113 in order.
+const CTCSS_DECIHERTZ: [u16; 50] = [
+    670, 693, 719, 744, 770, 797, 825, 854, 885, 915, 948, 974, 1000, 1035, 1072, 1109, 1148,
+    1188, 1230, 1273, 1318, 1365, 1413, 1462, 1514, 1567, 1598, 1622, 1655, 1679, 1713, 1738,
+    1773, 1799, 1835, 1862, 1899, 1928, 1966, 1995, 2035, 2065, 2107, 2181, 2257, 2291, 2336,
+    2418, 2503, 2541,
+];
+const CTCSS_FIRST_CODE: u8 = 64;
+
+/// DCS codes (octal digits written as decimal, 23 is "023"), for codes
+/// 128-231 in order.
+const DCS_CODES: [u16; 104] = [
+    23, 25, 26, 31, 32, 36, 43, 47, 51, 53, 54, 65, 71, 72, 73, 74, 114, 115, 116, 122, 125, 131,
+    132, 134, 143, 145, 152, 155, 156, 162, 165, 172, 174, 205, 212, 223, 225, 226, 243, 244, 245,
+    246, 251, 252, 255, 261, 263, 265, 266, 271, 274, 306, 311, 315, 325, 331, 332, 343, 346, 351,
+    356, 364, 365, 371, 411, 412, 413, 423, 431, 432, 445, 446, 452, 454, 455, 462, 464, 465, 466,
+    503, 506, 516, 523, 526, 532, 546, 565, 606, 612, 624, 627, 631, 632, 654, 662, 664, 703, 712,
+    723, 731, 732, 734, 743, 754,
+];
+const DCS_FIRST_CODE: u8 = 128;
+
+const NONE_CODE: u8 = 0;
+const SEARCH_CODE: u8 = 127;
+const NO_TONE_CODE: u8 = 240;
+
+/// The tone squelch setting of a channel, the CIN `[CTCSS/DCS]` field.
+// NoTone is the radio's own name for the setting
+#[allow(clippy::enum_variant_names)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum Tone {
+    /// Tone squelch off.
+    #[default]
+    None,
+    /// A CTCSS tone, in tenths of Hz (1035 is 103.5 Hz).
+    Ctcss(u16),
+    /// A DCS code, as its octal digits (23 is DCS 023).
+    Dcs(u16),
+    /// Open on any tone and show which one was received.
+    Search,
+    /// Only open for signals carrying no tone.
+    NoTone,
+}
+
+impl Tone {
+    /// Decodes a CIN tone code.
+    pub fn from_code(code: u8) -> Result<Self, ScannerError> {
+        let lookup = |table: &[u16], first: u8| {
+            code.checked_sub(first).and_then(|i| table.get(i as usize)).copied()
+        };
+        match code {
+            NONE_CODE => return Ok(Tone::None),
+            SEARCH_CODE => return Ok(Tone::Search),
+            NO_TONE_CODE => return Ok(Tone::NoTone),
+            _ => {}
+        }
+        if let Some(decihertz) = lookup(&CTCSS_DECIHERTZ, CTCSS_FIRST_CODE) {
+            return Ok(Tone::Ctcss(decihertz));
+        }
+        if let Some(digits) = lookup(&DCS_CODES, DCS_FIRST_CODE) {
+            return Ok(Tone::Dcs(digits));
+        }
+        Err(ScannerError::InvalidArgument(format!("Unknown tone code {}", code)))
+    }
+
+    /// The CIN tone code. Fails for a CTCSS tone or DCS code the scanner
+    /// doesn't support.
+    pub fn code(self) -> Result<u8, ScannerError> {
+        let position = |table: &[u16], value: u16| table.iter().position(|&v| v == value);
+        match self {
+            Tone::None => Ok(NONE_CODE),
+            Tone::Search => Ok(SEARCH_CODE),
+            Tone::NoTone => Ok(NO_TONE_CODE),
+            Tone::Ctcss(decihertz) => position(&CTCSS_DECIHERTZ, decihertz)
+                .map(|i| CTCSS_FIRST_CODE + i as u8)
+                .ok_or_else(|| {
+                    ScannerError::InvalidArgument(format!("{} is not a supported tone", self))
+                }),
+            Tone::Dcs(digits) => position(&DCS_CODES, digits)
+                .map(|i| DCS_FIRST_CODE + i as u8)
+                .ok_or_else(|| {
+                    ScannerError::InvalidArgument(format!("{} is not a supported code", self))
+                }),
+        }
+    }
+
+    /// Every setting the scanner supports, in code order.
+    pub fn all() -> impl Iterator<Item = Tone> {
+        std::iter::once(Tone::None)
+            .chain(CTCSS_DECIHERTZ.iter().map(|&t| Tone::Ctcss(t)))
+            .chain(std::iter::once(Tone::Search))
+            .chain(DCS_CODES.iter().map(|&d| Tone::Dcs(d)))
+            .chain(std::iter::once(Tone::NoTone))
+    }
+}
+
+impl fmt::Display for Tone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tone::None => write!(f, "None"),
+            Tone::Ctcss(t) => write!(f, "CTCSS {}.{}", t / 10, t % 10),
+            Tone::Dcs(d) => write!(f, "DCS {:03}", d),
+            Tone::Search => write!(f, "Search"),
+            Tone::NoTone => write!(f, "No Tone"),
+        }
+    }
+}
+
+/// Parses the names [`Tone`] displays as, such as `CTCSS 103.5` or
+/// `DCS 023`, ignoring case. Only tones the scanner supports are accepted.
+impl FromStr for Tone {
+    type Err = ScannerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let unknown = || ScannerError::InvalidArgument(format!("Unknown tone {:?}", s));
+        let upper = s.trim().to_ascii_uppercase();
+
+        let tone = if let Some(hz) = upper.strip_prefix("CTCSS") {
+            let (whole, tenth) = hz.trim().split_once('.').unwrap_or((hz.trim(), "0"));
+            let decihertz = match (whole.parse::<u16>(), tenth.parse::<u16>()) {
+                (Ok(whole), Ok(tenth)) if tenth < 10 => {
+                    whole.checked_mul(10).and_then(|d| d.checked_add(tenth))
+                }
+                _ => None,
+            };
+            Tone::Ctcss(decihertz.ok_or_else(unknown)?)
+        } else if let Some(code) = upper.strip_prefix("DCS") {
+            Tone::Dcs(code.trim().parse().map_err(|_| unknown())?)
+        } else {
+            match upper.as_str() {
+                "" | "NONE" | "OFF" => Tone::None,
+                "SEARCH" => Tone::Search,
+                "NO TONE" => Tone::NoTone,
+                _ => return Err(unknown()),
+            }
+        };
+        // Rejects tones outside the table
+        tone.code()?;
+        Ok(tone)
+    }
+}
+
+impl From<Tone> for String {
+    fn from(tone: Tone) -> Self {
+        tone.to_string()
+    }
+}
+
+impl TryFrom<String> for Tone {
+    type Error = ScannerError;
+
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        name.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_code_round_trips() {
+        assert_eq!(Tone::all().count(), 1 + 50 + 1 + 104 + 1);
+        for tone in Tone::all() {
+            let code = tone.code().unwrap();
+            assert_eq!(Tone::from_code(code).unwrap(), tone);
+            assert_eq!(tone.to_string().parse::<Tone>().unwrap(), tone);
+        }
+        assert_eq!(Tone::from_code(64).unwrap(), Tone::Ctcss(670));
+        assert_eq!(Tone::from_code(113).unwrap(), Tone::Ctcss(2541));
+        assert_eq!(Tone::from_code(128).unwrap(), Tone::Dcs(23));
+        assert_eq!(Tone::from_code(231).unwrap(), Tone::Dcs(754));
+        for code in [1, 63, 114, 126, 232, 239, 241, 255] {
+            assert!(Tone::from_code(code).is_err());
+        }
+    }
+
+    #[test]
+    fn test_parse_tone_names() {
+        assert_eq!("ctcss 103.5".parse::<Tone>().unwrap(), Tone::Ctcss(1035));
+        assert_eq!("CTCSS 100".parse::<Tone>().unwrap(), Tone::Ctcss(1000));
+        assert_eq!("DCS 023".parse::<Tone>().unwrap(), Tone::Dcs(23));
+        assert_eq!("no tone".parse::<Tone>().unwrap(), Tone::NoTone);
+        assert_eq!(Tone::Ctcss(1035).to_string(), "CTCSS 103.5");
+        assert!("CTCSS 103.6".parse::<Tone>().is_err());
+        assert!("DCS 024".parse::<Tone>().is_err());
+        assert!("PL 103.5".parse::<Tone>().is_err());
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::VecDeque;
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::time::{Duration, Instant};
+use serialport::SerialPort;
+use super::capture::ReplayTransport;
+use super::detect::find_scanner;
+
+/// A byte link to the scanner.
+///
+/// The scanner protocol is line based: each command is terminated with `\r`
+/// and so is each response. Implementations only have to move lines; all
+/// command semantics live in `ScannerClient`.
+pub trait ScannerTransport: Send {
+    /// Writes a single command line, appending the `\r` terminator.
+    fn write_line(&mut self, line: &str) -> io::Result<()>;
+
+    /// Reads a single `\r`-terminated response line.
+    ///
+    /// Fails with [`io::ErrorKind::TimedOut`] if no complete line arrives
+    /// within `timeout`, and [`io::ErrorKind::UnexpectedEof`] if the link
+    /// has closed.
+    fn read_line(&mut self, timeout: Duration) -> io::Result<String>;
+}
+
+/// Opens a fresh transport, used to reconnect after the link drops.
+pub type Connector = Box<dyn FnMut() -> io::Result<Box<dyn ScannerTransport>> + Send>;
+
+/// Returns a [`Connector`] that reopens `device` with [`open`].
+///
+/// With `auto` the scanner is searched for again on each reconnect, so it is
+/// found even if it comes back under a different device name.
+pub fn connector(device: &str) -> Connector {
+    let device = device.to_string();
+    Box::new(move || open(&device))
+}
+
+/// Opens a transport from a device string.
+///
+/// `auto` finds a USB-connected scanner (see [`find_scanner`]),
+/// `tcp://host:port` connects to a serial-over-TCP bridge such as `ser2net`,
+/// `replay:path` plays back a capture file (see [`ReplayTransport`]),
+/// anything else is treated as a serial device path.
+pub fn open(device: &str) -> io::Result<Box<dyn ScannerTransport>> {
+    if device == "auto" {
+        let path = find_scanner()?;
+        tracing::info!("Found scanner at {}", path);
+        Ok(Box::new(SerialTransport::open(&path)?))
+    } else if let Some(addr) = device.strip_prefix("tcp://") {
+        Ok(Box::new(TcpTransport::connect(addr)?))
+    } else if let Some(path) = device.strip_prefix("replay:") {
+        Ok(Box::new(ReplayTransport::open(path)?))
+    } else {
+        Ok(Box::new(SerialTransport::open(device)?))
+    }
+}
+
+/// Reads bytes one at a time until `\r`, failing if `timeout` passes first.
+///
+/// `reader` must have its own short read timeout so the overall deadline is
+/// honoured.
+fn read_cr_line(reader: &mut impl Read, timeout: Duration) -> io::Result<String> {
+    let mut response = String::new();
+    let mut buf = [0u8; 1];
+    let start = Instant::now();
+
+    loop {
+        if start.elapsed() > timeout {
+            return Err(io::Error::new(io::ErrorKind::TimedOut, "No response from scanner"));
+        }
+        match reader.read(&mut buf) {
+            Ok(n) if n > 0 => {
+                let c = buf[0] as char;
+                if c == '\r' {
+                    break;
+                }
+                if c != '\n' {
+                    response.push(c);
+                }
+            }
+            Ok(_) => return Err(io::ErrorKind::UnexpectedEof.into()),
+            Err(ref e)
+                if e.kind() == io::ErrorKind::TimedOut || e.kind() == io::ErrorKind::WouldBlock => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(response.trim().to_string())
+}
+
+pub struct SerialTransport {
+    port: Box<dyn SerialPort>,
+}
+
+impl SerialTransport {
+    pub fn open(device_path: &str) -> io::Result<Self> {
+        let port = serialport::new(device_path, 115_200)
+            .timeout(Duration::from_millis(100))
+            .open()?;
+
+        // Clear buffer
+        let _ = port.clear(serialport::ClearBuffer::All);
+
+        Ok(Self { port })
+    }
+}
+
+impl ScannerTransport for SerialTransport {
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        self.port.write_all(format!("{}\r", line).as_bytes())
+    }
+
+    fn read_line(&mut self, timeout: Duration) -> io::Result<String> {
+        read_cr_line(&mut self.port, timeout)
+    }
+}
+
+pub struct TcpTransport {
+    stream: TcpStream,
+}
+
+impl TcpTransport {
+    pub fn connect(addr: &str) -> io::Result<Self> {
+        let stream = TcpStream::connect(addr)?;
+        stream.set_read_timeout(Some(Duration::from_millis(100)))?;
+        stream.set_nodelay(true)?;
+        Ok(Self { stream })
+    }
+}
+
+impl ScannerTransport for TcpTransport {
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        self.stream.write_all(format!("{}\r", line).as_bytes())
+    }
+
+    fn read_line(&mut self, timeout: Duration) -> io::Result<String> {
+        read_cr_line(&mut self.stream, timeout)
+    }
+}
+
+type Responder = Box<dyn FnMut(&str) -> Option<String> + Send>;
+
+/// An in-process transport that answers each command with a closure.
+///
+/// Useful for tests and for running against an emulated scanner without a
+/// serial link.
+pub struct MemoryTransport {
+    responder: Responder,
+    pending: VecDeque<String>,
+}
+
+#[allow(dead_code)]
+impl MemoryTransport {
+    pub fn new(responder: impl FnMut(&str) -> Option<String> + Send + 'static) -> Self {
+        Self {
+            responder: Box::new(responder),
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Queues a line that will be read before any response, as if the
+    /// scanner had sent it unprompted.
+    pub fn push_line(&mut self, line: &str) {
+        self.pending.push_back(line.to_string());
+    }
+}
+
+impl ScannerTransport for MemoryTransport {
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        if let Some(response) = (self.responder)(line) {
+            self.pending.push_back(response);
+        }
+        Ok(())
+    }
+
+    fn read_line(&mut self, _timeout: Duration) -> io::Result<String> {
+        self.pending
+            .pop_front()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::TimedOut, "No response from scanner"))
+    }
+}
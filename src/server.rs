@@ -1,42 +1,471 @@
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tonic::{Request, Response, Status};
-use crate::scanner::ScannerClient;
+use crate::scanner::{Frequency, ScannerClient, ScannerError};
+use crate::scanner::channel::{ChannelInfo, ChannelName, Delay, Modulation};
+use crate::scanner::close_call::{
+    CLOSE_CALL_BANDS, CloseCallDetector, CloseCallHit, CloseCallMode, CloseCallSettings,
+};
+use crate::scanner::display::{CharStyle, DisplayStatus};
+use crate::scanner::keys::{Key, KeyAction};
+use crate::scanner::tone::Tone;
+use crate::scanner::model::Capabilities;
+use crate::scanner::search::{SEARCH_RANGE_COUNT, SearchRange, ServiceGroup};
+use crate::scanner::status::ScanStatus;
+use crate::scanner::actor::{self, ScannerHandle};
 use ubc125_grpc::ubc125::v1::system_info_service_server::SystemInfoService;
 use ubc125_grpc::ubc125::v1::scanner_control_service_server::ScannerControlService;
 use ubc125_grpc::ubc125::v1::{
+    self as proto,
     GetAudioSettingsRequest, GetAudioSettingsResponse,
     GetModelInfoRequest, GetModelInfoResponse,
     GetFirmwareVersionRequest, GetFirmwareVersionResponse,
+    WatchConnectionRequest, WatchConnectionResponse, ConnectionState,
     StartScanRequest, StartScanResponse,
     HoldScanRequest, HoldScanResponse,
     GetEnabledBanksRequest, GetEnabledBanksResponse,
     SetEnabledBanksRequest, SetEnabledBanksResponse,
     GetStatusRequest, GetStatusResponse,
+    GetDisplayRequest, GetDisplayResponse,
     GetChannelRequest, GetChannelResponse,
     SetChannelRequest, SetChannelResponse,
     DeleteChannelRequest, DeleteChannelResponse,
+    ListSearchRangesRequest, ListSearchRangesResponse,
+    SetSearchRangeRequest, SetSearchRangeResponse,
+    SetEnabledSearchRangesRequest, SetEnabledSearchRangesResponse,
+    StartCustomSearchRequest, StartCustomSearchResponse,
+    GetCloseCallSettingsRequest, GetCloseCallSettingsResponse,
+    SetCloseCallSettingsRequest, SetCloseCallSettingsResponse,
+    WatchCloseCallHitsRequest, WatchCloseCallHitsResponse,
+    ListServiceGroupsRequest, ListServiceGroupsResponse,
+    SetServiceGroupEnabledRequest, SetServiceGroupEnabledResponse,
+    StartServiceSearchRequest, StartServiceSearchResponse,
+    PressKeysRequest, PressKeysResponse,
 };
 use tokio_stream::wrappers::ReceiverStream;
 
+/// How often the GetStatus, GetDisplay and WatchCloseCallHits streams poll
+/// the scanner.
+const STATUS_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+impl From<ScannerError> for Status {
+    fn from(e: ScannerError) -> Self {
+        let message = e.to_string();
+        match e {
+            ScannerError::Timeout(_) => Status::deadline_exceeded(message),
+            ScannerError::FormatError(_) | ScannerError::InvalidArgument(_) => {
+                Status::invalid_argument(message)
+            }
+            ScannerError::NotNow(_) => Status::failed_precondition(message),
+            ScannerError::UnexpectedResponse { .. } => Status::internal(message),
+            ScannerError::Disconnected => Status::unavailable(message),
+            ScannerError::Unsupported { .. } => Status::unimplemented(message),
+            ScannerError::Io(_) => Status::unavailable(message),
+        }
+    }
+}
+
+impl From<Frequency> for proto::Frequency {
+    fn from(frequency: Frequency) -> Self {
+        proto::Frequency { hz: frequency.hz() }
+    }
+}
+
+impl From<&Capabilities> for proto::ModelCapabilities {
+    fn from(capabilities: &Capabilities) -> Self {
+        proto::ModelCapabilities {
+            channel_count: capabilities.channel_count.into(),
+            bank_count: capabilities.bank_count as u32,
+            bands: capabilities
+                .bands
+                .iter()
+                .map(|band| proto::Band {
+                    lower: Some(band.lower.into()),
+                    upper: Some(band.upper.into()),
+                    steps: band.steps.iter().map(|step| step.to_string()).collect(),
+                })
+                .collect(),
+            extra_commands: capabilities.extra_commands.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+}
+
+impl TryFrom<proto::Frequency> for Frequency {
+    type Error = ScannerError;
+
+    fn try_from(frequency: proto::Frequency) -> Result<Self, Self::Error> {
+        Frequency::from_exact_hz(frequency.hz)
+    }
+}
+
+impl From<Modulation> for proto::Modulation {
+    fn from(modulation: Modulation) -> Self {
+        match modulation {
+            Modulation::Auto => proto::Modulation::Auto,
+            Modulation::Am => proto::Modulation::Am,
+            Modulation::Fm => proto::Modulation::Fm,
+            Modulation::Nfm => proto::Modulation::Nfm,
+        }
+    }
+}
+
+impl From<Tone> for proto::Tone {
+    fn from(tone: Tone) -> Self {
+        let mut message = proto::Tone {
+            name: tone.to_string(),
+            ..Default::default()
+        };
+        let kind = match tone {
+            Tone::None => proto::ToneKind::None,
+            Tone::Ctcss(decihertz) => {
+                message.ctcss_decihertz = decihertz.into();
+                proto::ToneKind::Ctcss
+            }
+            Tone::Dcs(digits) => {
+                message.dcs_code = format!("{:03}", digits);
+                proto::ToneKind::Dcs
+            }
+            Tone::Search => proto::ToneKind::Search,
+            Tone::NoTone => proto::ToneKind::NoTone,
+        };
+        message.set_kind(kind);
+        message
+    }
+}
+
+impl TryFrom<proto::Tone> for Tone {
+    type Error = ScannerError;
+
+    fn try_from(tone: proto::Tone) -> Result<Self, Self::Error> {
+        let invalid = |msg: &str| ScannerError::InvalidArgument(msg.to_string());
+        let tone = match tone.kind() {
+            proto::ToneKind::Unspecified | proto::ToneKind::None => Tone::None,
+            proto::ToneKind::Ctcss => Tone::Ctcss(
+                tone.ctcss_decihertz.try_into().map_err(|_| invalid("Invalid CTCSS tone"))?,
+            ),
+            proto::ToneKind::Dcs => {
+                Tone::Dcs(tone.dcs_code.parse().map_err(|_| invalid("Invalid DCS code"))?)
+            }
+            proto::ToneKind::Search => Tone::Search,
+            proto::ToneKind::NoTone => Tone::NoTone,
+        };
+        // Only tones in the scanner's table are accepted
+        tone.code()?;
+        Ok(tone)
+    }
+}
+
+impl From<ChannelInfo> for proto::Channel {
+    fn from(channel: ChannelInfo) -> Self {
+        proto::Channel {
+            index: channel.index,
+            name: channel.name.into(),
+            frequency: Some(channel.frequency.into()),
+            modulation: proto::Modulation::from(channel.modulation).into(),
+            tone: Some(channel.tone.into()),
+            delay: channel.delay.seconds().into(),
+            lockout: channel.lockout,
+            priority: channel.priority,
+        }
+    }
+}
+
+impl TryFrom<proto::Channel> for ChannelInfo {
+    type Error = ScannerError;
+
+    fn try_from(channel: proto::Channel) -> Result<Self, Self::Error> {
+        let invalid = |msg: &str| ScannerError::InvalidArgument(msg.to_string());
+        let modulation = match channel.modulation() {
+            proto::Modulation::Unspecified | proto::Modulation::Auto => Modulation::Auto,
+            proto::Modulation::Am => Modulation::Am,
+            proto::Modulation::Fm => Modulation::Fm,
+            proto::Modulation::Nfm => Modulation::Nfm,
+        };
+        Ok(ChannelInfo {
+            index: channel.index,
+            name: ChannelName::transliterate(&channel.name)?,
+            frequency: channel.frequency.map(Frequency::try_from).transpose()?.unwrap_or_default(),
+            modulation,
+            tone: channel.tone.map(Tone::try_from).transpose()?.unwrap_or_default(),
+            delay: Delay::new(channel.delay.try_into().map_err(|_| invalid("Invalid delay"))?)?,
+            lockout: channel.lockout,
+            priority: channel.priority,
+        })
+    }
+}
+
+impl From<ScanStatus> for GetStatusResponse {
+    fn from(status: ScanStatus) -> Self {
+        GetStatusResponse {
+            frequency: Some(status.frequency.into()),
+            modulation: proto::Modulation::from(status.modulation).into(),
+            attenuator: status.attenuator,
+            signal_status: status.signal_status.into(),
+            // Depends on the model, so filled in by the caller
+            bank: String::new(),
+            channel_name: status.channel_name,
+            signal_detected: status.squelch_open,
+            muted: status.muted,
+            channel_index: status.channel.unwrap_or_default(),
+            raw_response: String::new(),
+        }
+    }
+}
+
+impl TryFrom<proto::SearchRange> for SearchRange {
+    type Error = ScannerError;
+
+    fn try_from(range: proto::SearchRange) -> Result<Self, Self::Error> {
+        let range = SearchRange {
+            index: range.index,
+            lower: range.lower.map(Frequency::try_from).transpose()?.unwrap_or_default(),
+            upper: range.upper.map(Frequency::try_from).transpose()?.unwrap_or_default(),
+        };
+        // Checks the index and limits
+        range.to_command()?;
+        Ok(range)
+    }
+}
+
+impl From<ServiceGroup> for proto::ServiceGroup {
+    fn from(group: ServiceGroup) -> Self {
+        match group {
+            ServiceGroup::Police => proto::ServiceGroup::Police,
+            ServiceGroup::FireEmergency => proto::ServiceGroup::FireEmergency,
+            ServiceGroup::Ham => proto::ServiceGroup::Ham,
+            ServiceGroup::Marine => proto::ServiceGroup::Marine,
+            ServiceGroup::Railroad => proto::ServiceGroup::Railroad,
+            ServiceGroup::Air => proto::ServiceGroup::Air,
+            ServiceGroup::Cb => proto::ServiceGroup::Cb,
+            ServiceGroup::FrsGmrs => proto::ServiceGroup::FrsGmrs,
+            ServiceGroup::Racing => proto::ServiceGroup::Racing,
+            ServiceGroup::Special => proto::ServiceGroup::Special,
+        }
+    }
+}
+
+impl TryFrom<proto::ServiceGroup> for ServiceGroup {
+    type Error = ScannerError;
+
+    fn try_from(group: proto::ServiceGroup) -> Result<Self, Self::Error> {
+        Ok(match group {
+            proto::ServiceGroup::Unspecified => {
+                return Err(ScannerError::InvalidArgument("group is required".to_string()));
+            }
+            proto::ServiceGroup::Police => ServiceGroup::Police,
+            proto::ServiceGroup::FireEmergency => ServiceGroup::FireEmergency,
+            proto::ServiceGroup::Ham => ServiceGroup::Ham,
+            proto::ServiceGroup::Marine => ServiceGroup::Marine,
+            proto::ServiceGroup::Railroad => ServiceGroup::Railroad,
+            proto::ServiceGroup::Air => ServiceGroup::Air,
+            proto::ServiceGroup::Cb => ServiceGroup::Cb,
+            proto::ServiceGroup::FrsGmrs => ServiceGroup::FrsGmrs,
+            proto::ServiceGroup::Racing => ServiceGroup::Racing,
+            proto::ServiceGroup::Special => ServiceGroup::Special,
+        })
+    }
+}
+
+impl TryFrom<proto::Key> for Key {
+    type Error = ScannerError;
+
+    fn try_from(key: proto::Key) -> Result<Self, Self::Error> {
+        Ok(match key {
+            proto::Key::Unspecified => {
+                return Err(ScannerError::InvalidArgument("key is required".to_string()));
+            }
+            proto::Key::Menu => Key::Menu,
+            proto::Key::Func => Key::Func,
+            proto::Key::Hold => Key::Hold,
+            proto::Key::Scan => Key::Scan,
+            proto::Key::Search => Key::Search,
+            proto::Key::Lockout => Key::Lockout,
+            proto::Key::Priority => Key::Priority,
+            proto::Key::Weather => Key::Weather,
+            proto::Key::Digit0 => Key::Digit0,
+            proto::Key::Digit1 => Key::Digit1,
+            proto::Key::Digit2 => Key::Digit2,
+            proto::Key::Digit3 => Key::Digit3,
+            proto::Key::Digit4 => Key::Digit4,
+            proto::Key::Digit5 => Key::Digit5,
+            proto::Key::Digit6 => Key::Digit6,
+            proto::Key::Digit7 => Key::Digit7,
+            proto::Key::Digit8 => Key::Digit8,
+            proto::Key::Digit9 => Key::Digit9,
+            proto::Key::Decimal => Key::Decimal,
+            proto::Key::Enter => Key::Enter,
+            proto::Key::Left => Key::Left,
+            proto::Key::Right => Key::Right,
+            proto::Key::Push => Key::Push,
+        })
+    }
+}
+
+impl From<proto::KeyAction> for KeyAction {
+    fn from(action: proto::KeyAction) -> Self {
+        match action {
+            proto::KeyAction::Unspecified | proto::KeyAction::Press => KeyAction::Press,
+            proto::KeyAction::LongPress => KeyAction::LongPress,
+            proto::KeyAction::Hold => KeyAction::Hold,
+            proto::KeyAction::Release => KeyAction::Release,
+        }
+    }
+}
+
+impl From<CloseCallSettings> for proto::CloseCallSettings {
+    fn from(settings: CloseCallSettings) -> Self {
+        let mode = match settings.mode {
+            CloseCallMode::Off => proto::CloseCallMode::Off,
+            CloseCallMode::Priority => proto::CloseCallMode::Priority,
+            CloseCallMode::DoNotDisturb => proto::CloseCallMode::DoNotDisturb,
+        };
+        proto::CloseCallSettings {
+            mode: mode.into(),
+            alert_beep: settings.alert_beep,
+            alert_light: settings.alert_light,
+            bands: settings.bands.to_vec(),
+            lockout: settings.lockout,
+        }
+    }
+}
+
+impl TryFrom<proto::CloseCallSettings> for CloseCallSettings {
+    type Error = ScannerError;
+
+    fn try_from(settings: proto::CloseCallSettings) -> Result<Self, Self::Error> {
+        let mode = match settings.mode() {
+            proto::CloseCallMode::Unspecified | proto::CloseCallMode::Off => CloseCallMode::Off,
+            proto::CloseCallMode::Priority => CloseCallMode::Priority,
+            proto::CloseCallMode::DoNotDisturb => CloseCallMode::DoNotDisturb,
+        };
+        let bands = settings.bands.try_into().map_err(|_| {
+            ScannerError::InvalidArgument(format!(
+                "Expected {} Close Call bands",
+                CLOSE_CALL_BANDS.len()
+            ))
+        })?;
+        Ok(CloseCallSettings {
+            mode,
+            alert_beep: settings.alert_beep,
+            alert_light: settings.alert_light,
+            bands,
+            lockout: settings.lockout,
+        })
+    }
+}
+
+impl From<CloseCallHit> for WatchCloseCallHitsResponse {
+    fn from(hit: CloseCallHit) -> Self {
+        WatchCloseCallHitsResponse {
+            frequency: Some(hit.frequency.into()),
+            modulation: proto::Modulation::from(hit.modulation).into(),
+        }
+    }
+}
+
+impl From<DisplayStatus> for GetDisplayResponse {
+    fn from(display: DisplayStatus) -> Self {
+        let lines = display
+            .lines
+            .into_iter()
+            .map(|line| proto::DisplayLine {
+                text: line.text,
+                styles: line
+                    .styles
+                    .into_iter()
+                    .map(|style| {
+                        let style = match style {
+                            CharStyle::Normal => proto::CharStyle::Normal,
+                            CharStyle::Reverse => proto::CharStyle::Reverse,
+                            CharStyle::Underline => proto::CharStyle::Underline,
+                        };
+                        style.into()
+                    })
+                    .collect(),
+                large: line.large,
+            })
+            .collect();
+        GetDisplayResponse {
+            lines,
+            squelch_open: display.squelch_open,
+            muted: display.muted,
+            battery_low: display.battery_low,
+            signal_level: display.signal_level.into(),
+        }
+    }
+}
+
+/// Runs `poll` every [`STATUS_POLL_INTERVAL`] and streams the results until
+/// the client goes away. Polls that return `None` send nothing.
+///
+/// Failed polls are skipped rather than ending the stream; a dropped link
+/// is reported by WatchConnection, and polling carries on once it is back.
+fn poll_stream<T, F>(scanner: ScannerHandle, poll: F) -> ReceiverStream<Result<T, Status>>
+where
+    T: Send + 'static,
+    F: Fn(&mut ScannerClient) -> Result<Option<T>, ScannerError> + Clone + Send + 'static,
+{
+    let (tx, rx) = tokio::sync::mpsc::channel(4);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(STATUS_POLL_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        while !tx.is_closed() {
+            interval.tick().await;
+            let polled = match scanner.call(poll.clone()).await {
+                Ok(Some(polled)) => polled,
+                Ok(None) => continue,
+                Err(e) => {
+                    tracing::debug!("Status poll failed: {}", e);
+                    continue;
+                }
+            };
+            if tx.send(Ok(polled)).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    ReceiverStream::new(rx)
+}
+
 #[derive(Clone)]
 pub struct ScannerServer {
-    pub client: Arc<Mutex<ScannerClient>>,
+    pub scanner: ScannerHandle,
+}
+
+impl From<actor::ConnectionState> for WatchConnectionResponse {
+    fn from(state: actor::ConnectionState) -> Self {
+        match state {
+            actor::ConnectionState::Connected => WatchConnectionResponse {
+                state: ConnectionState::Connected.into(),
+                reconnect_attempts: 0,
+            },
+            actor::ConnectionState::Disconnected { attempts } => WatchConnectionResponse {
+                state: ConnectionState::Disconnected.into(),
+                reconnect_attempts: attempts,
+            },
+        }
+    }
 }
 
 #[tonic::async_trait]
 impl SystemInfoService for ScannerServer {
+    type WatchConnectionStream = ReceiverStream<Result<WatchConnectionResponse, Status>>;
+
     async fn get_model_info(
         &self,
         request: Request<GetModelInfoRequest>,
     ) -> Result<Response<GetModelInfoResponse>, Status> {
         println!("Got a request: {:?}", request);
-        let client = self.client.clone();
-        let res = tokio::task::spawn_blocking(move || {
-            let mut client = client.lock().unwrap();
-            client.send_command("MDL").map_err(|e| Status::internal(e.to_string()))
-        }).await.unwrap()?;
+        let model = self.scanner.call(|client| client.model().cloned()).await?;
 
-        Ok(Response::new(GetModelInfoResponse { result: res }))
+        Ok(Response::new(GetModelInfoResponse {
+            result: format!("MDL,{}", model.name),
+            known: model.is_known(),
+            capabilities: Some(model.capabilities.into()),
+            name: model.name,
+        }))
     }
 
     async fn get_firmware_version(
@@ -44,88 +473,333 @@ impl SystemInfoService for ScannerServer {
         request: Request<GetFirmwareVersionRequest>,
     ) -> Result<Response<GetFirmwareVersionResponse>, Status> {
         println!("Got a request: {:?}", request);
-        let client = self.client.clone();
-        let res = tokio::task::spawn_blocking(move || {
-            let mut client = client.lock().unwrap();
-            client.send_command("VER").map_err(|e| Status::internal(e.to_string()))
-        }).await.unwrap()?;
+        let model = self.scanner.call(|client| client.model().cloned()).await?;
+
+        Ok(Response::new(GetFirmwareVersionResponse { result: format!("VER,{}", model.firmware) }))
+    }
+
+    async fn watch_connection(
+        &self,
+        _request: Request<WatchConnectionRequest>,
+    ) -> Result<Response<Self::WatchConnectionStream>, Status> {
+        let mut connection = self.scanner.connection();
+        let (tx, rx) = tokio::sync::mpsc::channel(4);
+
+        tokio::spawn(async move {
+            loop {
+                let state = *connection.borrow_and_update();
+                if tx.send(Ok(state.into())).await.is_err() {
+                    break;
+                }
+                if connection.changed().await.is_err() {
+                    break;
+                }
+            }
+        });
 
-        Ok(Response::new(GetFirmwareVersionResponse { result: res }))
+        Ok(Response::new(ReceiverStream::new(rx)))
     }
 }
 
 #[tonic::async_trait]
 impl ScannerControlService for ScannerServer {
     type GetStatusStream = ReceiverStream<Result<GetStatusResponse, Status>>;
+    type GetDisplayStream = ReceiverStream<Result<GetDisplayResponse, Status>>;
+    type WatchCloseCallHitsStream = ReceiverStream<Result<WatchCloseCallHitsResponse, Status>>;
 
     async fn get_audio_settings(
         &self,
         _request: Request<GetAudioSettingsRequest>,
     ) -> Result<Response<GetAudioSettingsResponse>, Status> {
-        let client = self.client.clone();
-        let (vol, sql) = tokio::task::spawn_blocking(move || {
-            let mut client = client.lock().unwrap();
-            let vol = client.get_volume().map_err(|e| Status::internal(e.to_string()))?;
-            let sql = client.get_squelch().map_err(|e| Status::internal(e.to_string()))?;
-            Ok::<_, Status>((vol, sql))
-        }).await.unwrap()?;
+        let (vol, sql) = self.scanner
+            .call(|client| Ok((client.get_volume()?, client.get_squelch()?)))
+            .await?;
 
-        Ok(Response::new(GetAudioSettingsResponse { volume: vol, squelch: sql }))
+        Ok(Response::new(GetAudioSettingsResponse {
+            volume: vol as u32,
+            squelch: sql as u32,
+        }))
     }
 
     async fn start_scan(
         &self,
         _request: Request<StartScanRequest>,
     ) -> Result<Response<StartScanResponse>, Status> {
-        Err(Status::unimplemented("Not implemented"))
+        self.scanner.call(|client| client.press(Key::Scan, KeyAction::Press)).await?;
+        Ok(Response::new(StartScanResponse {}))
     }
 
     async fn hold_scan(
         &self,
         _request: Request<HoldScanRequest>,
     ) -> Result<Response<HoldScanResponse>, Status> {
-        Err(Status::unimplemented("Not implemented"))
+        self.scanner.call(|client| client.press(Key::Hold, KeyAction::Press)).await?;
+        Ok(Response::new(HoldScanResponse {}))
     }
 
     async fn get_enabled_banks(
         &self,
         _request: Request<GetEnabledBanksRequest>,
     ) -> Result<Response<GetEnabledBanksResponse>, Status> {
-        Err(Status::unimplemented("Not implemented"))
+        let banks = self.scanner
+            .call(|client| client.program()?.resume_scan().get_scan_banks())
+            .await?;
+
+        Ok(Response::new(GetEnabledBanksResponse { banks }))
     }
 
     async fn set_enabled_banks(
         &self,
-        _request: Request<SetEnabledBanksRequest>,
+        request: Request<SetEnabledBanksRequest>,
     ) -> Result<Response<SetEnabledBanksResponse>, Status> {
-        Err(Status::unimplemented("Not implemented"))
+        // One per bank of the model, checked by set_scan_banks
+        let banks = request.into_inner().banks;
+        self.scanner
+            .call(move |client| client.program()?.resume_scan().set_scan_banks(&banks))
+            .await?;
+
+        Ok(Response::new(SetEnabledBanksResponse {}))
     }
 
     async fn get_status(
         &self,
         _request: Request<GetStatusRequest>,
     ) -> Result<Response<Self::GetStatusStream>, Status> {
-        Err(Status::unimplemented("Not implemented"))
+        Ok(Response::new(poll_stream(self.scanner.clone(), |client| {
+            let line = client.send_command("GLG")?;
+            let status = ScanStatus::parse(&line)?;
+            let bank = status.as_ref().and_then(|s| s.bank(client.capabilities()));
+            Ok(Some(GetStatusResponse {
+                bank: bank.map(|b| b.to_string()).unwrap_or_default(),
+                raw_response: line,
+                ..status.map(GetStatusResponse::from).unwrap_or_default()
+            }))
+        })))
+    }
+
+    async fn get_display(
+        &self,
+        _request: Request<GetDisplayRequest>,
+    ) -> Result<Response<Self::GetDisplayStream>, Status> {
+        Ok(Response::new(poll_stream(self.scanner.clone(), |client| {
+            Ok(Some(client.display_status()?.into()))
+        })))
     }
 
     async fn get_channel(
         &self,
-        _request: Request<GetChannelRequest>,
+        request: Request<GetChannelRequest>,
     ) -> Result<Response<GetChannelResponse>, Status> {
-        Err(Status::unimplemented("Not implemented"))
+        let index = request.into_inner().index;
+        let channel = self.scanner
+            .call(move |client| client.program()?.resume_scan().get_channel(index))
+            .await?;
+
+        Ok(Response::new(GetChannelResponse { channel: Some(channel.into()) }))
     }
 
     async fn set_channel(
         &self,
-        _request: Request<SetChannelRequest>,
+        request: Request<SetChannelRequest>,
     ) -> Result<Response<SetChannelResponse>, Status> {
-        Err(Status::unimplemented("Not implemented"))
+        let channel = request
+            .into_inner()
+            .channel
+            .ok_or_else(|| Status::invalid_argument("channel is required"))?;
+        let channel = ChannelInfo::try_from(channel)?;
+        self.scanner
+            .call(move |client| client.program()?.resume_scan().set_channel(&channel))
+            .await?;
+
+        Ok(Response::new(SetChannelResponse {}))
     }
 
     async fn delete_channel(
         &self,
-        _request: Request<DeleteChannelRequest>,
+        request: Request<DeleteChannelRequest>,
     ) -> Result<Response<DeleteChannelResponse>, Status> {
-        Err(Status::unimplemented("Not implemented"))
+        let index = request.into_inner().index;
+        self.scanner
+            .call(move |client| client.program()?.resume_scan().delete_channel(index))
+            .await?;
+
+        Ok(Response::new(DeleteChannelResponse {}))
+    }
+
+    async fn list_search_ranges(
+        &self,
+        _request: Request<ListSearchRangesRequest>,
+    ) -> Result<Response<ListSearchRangesResponse>, Status> {
+        let (ranges, enabled) = self.scanner
+            .call(|client| {
+                let mut session = client.program()?.resume_scan();
+                Ok((session.get_search_ranges()?, session.get_search_groups()?))
+            })
+            .await?;
+
+        let ranges = ranges
+            .into_iter()
+            .zip(enabled)
+            .map(|(range, enabled)| proto::SearchRange {
+                index: range.index,
+                lower: Some(range.lower.into()),
+                upper: Some(range.upper.into()),
+                enabled,
+            })
+            .collect();
+        Ok(Response::new(ListSearchRangesResponse { ranges }))
+    }
+
+    async fn set_search_range(
+        &self,
+        request: Request<SetSearchRangeRequest>,
+    ) -> Result<Response<SetSearchRangeResponse>, Status> {
+        let range = request
+            .into_inner()
+            .range
+            .ok_or_else(|| Status::invalid_argument("range is required"))?;
+        let enabled = range.enabled;
+        let range = SearchRange::try_from(range)?;
+        self.scanner
+            .call(move |client| {
+                let mut session = client.program()?.resume_scan();
+                session.set_search_range(&range)?;
+                let mut groups = session.get_search_groups()?;
+                let slot = &mut groups[range.index as usize - 1];
+                if *slot != enabled {
+                    *slot = enabled;
+                    session.set_search_groups(&groups)?;
+                }
+                Ok(())
+            })
+            .await?;
+
+        Ok(Response::new(SetSearchRangeResponse {}))
     }
-}
\ No newline at end of file
+
+    async fn set_enabled_search_ranges(
+        &self,
+        request: Request<SetEnabledSearchRangesRequest>,
+    ) -> Result<Response<SetEnabledSearchRangesResponse>, Status> {
+        let ranges: [bool; SEARCH_RANGE_COUNT] =
+            request.into_inner().ranges.try_into().map_err(|_| {
+                Status::invalid_argument(format!("Expected {} search ranges", SEARCH_RANGE_COUNT))
+            })?;
+        self.scanner
+            .call(move |client| client.program()?.resume_scan().set_search_groups(&ranges))
+            .await?;
+
+        Ok(Response::new(SetEnabledSearchRangesResponse {}))
+    }
+
+    async fn start_custom_search(
+        &self,
+        _request: Request<StartCustomSearchRequest>,
+    ) -> Result<Response<StartCustomSearchResponse>, Status> {
+        self.scanner.call(|client| client.start_custom_search()).await?;
+        Ok(Response::new(StartCustomSearchResponse {}))
+    }
+
+    async fn list_service_groups(
+        &self,
+        _request: Request<ListServiceGroupsRequest>,
+    ) -> Result<Response<ListServiceGroupsResponse>, Status> {
+        let groups = self.scanner.call(|client| client.get_service_groups()).await?;
+        let groups = groups
+            .iter()
+            .map(|(group, enabled)| proto::ServiceGroupState {
+                group: proto::ServiceGroup::from(group).into(),
+                name: group.to_string(),
+                enabled,
+            })
+            .collect();
+        Ok(Response::new(ListServiceGroupsResponse { groups }))
+    }
+
+    async fn set_service_group_enabled(
+        &self,
+        request: Request<SetServiceGroupEnabledRequest>,
+    ) -> Result<Response<SetServiceGroupEnabledResponse>, Status> {
+        let request = request.into_inner();
+        let group = ServiceGroup::try_from(request.group())?;
+        let enabled = request.enabled;
+        self.scanner
+            .call(move |client| {
+                let mut session = client.program()?.resume_scan();
+                let mut groups = session.get_service_groups()?;
+                if groups.contains(group) != enabled {
+                    groups.set(group, enabled);
+                    session.set_service_groups(&groups)?;
+                }
+                Ok(())
+            })
+            .await?;
+
+        Ok(Response::new(SetServiceGroupEnabledResponse {}))
+    }
+
+    async fn start_service_search(
+        &self,
+        _request: Request<StartServiceSearchRequest>,
+    ) -> Result<Response<StartServiceSearchResponse>, Status> {
+        self.scanner.call(|client| client.start_service_search()).await?;
+        Ok(Response::new(StartServiceSearchResponse {}))
+    }
+
+    async fn press_keys(
+        &self,
+        request: Request<PressKeysRequest>,
+    ) -> Result<Response<PressKeysResponse>, Status> {
+        let presses = request
+            .into_inner()
+            .keys
+            .into_iter()
+            .map(|press| Ok((Key::try_from(press.key())?, press.action().into())))
+            .collect::<Result<Vec<_>, ScannerError>>()?;
+        self.scanner.call(move |client| client.press_keys(&presses)).await?;
+        Ok(Response::new(PressKeysResponse {}))
+    }
+
+    async fn get_close_call_settings(
+        &self,
+        _request: Request<GetCloseCallSettingsRequest>,
+    ) -> Result<Response<GetCloseCallSettingsResponse>, Status> {
+        let settings = self.scanner.call(|client| client.get_close_call()).await?;
+        Ok(Response::new(GetCloseCallSettingsResponse {
+            settings: Some(settings.into()),
+        }))
+    }
+
+    async fn set_close_call_settings(
+        &self,
+        request: Request<SetCloseCallSettingsRequest>,
+    ) -> Result<Response<SetCloseCallSettingsResponse>, Status> {
+        let settings = request
+            .into_inner()
+            .settings
+            .ok_or_else(|| Status::invalid_argument("settings is required"))?;
+        let settings = CloseCallSettings::try_from(settings)?;
+        self.scanner
+            .call(move |client| client.set_close_call(&settings))
+            .await?;
+
+        Ok(Response::new(SetCloseCallSettingsResponse {}))
+    }
+
+    async fn watch_close_call_hits(
+        &self,
+        _request: Request<WatchCloseCallHitsRequest>,
+    ) -> Result<Response<Self::WatchCloseCallHitsStream>, Status> {
+        // One detector per stream, so each caller sees every hit once
+        let detector = Arc::new(Mutex::new(CloseCallDetector::default()));
+        Ok(Response::new(poll_stream(self.scanner.clone(), move |client| {
+            let status = ScanStatus::parse(&client.send_command("GLG")?)?;
+            let display = client.display_status()?;
+            let hit = detector
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .update(status.as_ref(), &display);
+            Ok(hit.map(WatchCloseCallHitsResponse::from))
+        })))
+    }
+}
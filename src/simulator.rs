@@ -0,0 +1,774 @@
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::scanner::Frequency;
+use crate::scanner::display::LINE_WIDTH;
+use crate::scanner::tone::Tone;
+
+const CHANNEL_COUNT: usize = 500;
+const CHANNELS_PER_BANK: usize = 50;
+const MODULATIONS: [&str; 4] = ["AUTO", "AM", "FM", "NFM"];
+const DELAYS: [&str; 8] = ["-10", "-5", "0", "1", "2", "3", "4", "5"];
+/// The factory custom search ranges, in the 8-digit form.
+const SEARCH_RANGES: [(&str, &str); 10] = [
+    ("00250000", "00279950"),
+    ("00280000", "00299950"),
+    ("00300000", "00499950"),
+    ("00500000", "00540000"),
+    ("01080000", "01369916"),
+    ("01370000", "01439950"),
+    ("01440000", "01479950"),
+    ("01480000", "01619950"),
+    ("02250000", "03800000"),
+    ("04000000", "05120000"),
+];
+/// How far a search moves each tick, in units of 100 Hz.
+const SEARCH_STEP: u32 = 125;
+/// A few frequencies for each service search group, in `SSG` order and
+/// units of 100 Hz.
+const SERVICE_FREQUENCIES: [&[u32]; 10] = [
+    &[1_554_750, 4_600_250],
+    &[1_542_800, 1_544_300],
+    &[1_455_000, 4_335_000],
+    &[1_568_000, 1_563_000],
+    &[1_602_150, 1_615_500],
+    &[1_215_000, 2_430_000],
+    &[270_650, 271_850],
+    &[4_625_625, 4_675_625],
+    &[4_612_125],
+    &[1_518_200, 1_545_700],
+];
+/// Where Close Call finds something in each `CC_BAND`, in units of 100 Hz.
+const CLOSE_CALL_FREQUENCIES: [u32; 5] = [296_000, 1_187_000, 1_625_500, 2_430_000, 4_625_625];
+
+#[derive(Clone)]
+struct SimChannel {
+    name: String,
+    frequency: String,
+    modulation: String,
+    tone: String,
+    delay: String,
+    lockout: String,
+    priority: String,
+}
+
+impl Default for SimChannel {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            frequency: "00000000".to_string(),
+            modulation: "AUTO".to_string(),
+            tone: "0".to_string(),
+            delay: "2".to_string(),
+            lockout: "0".to_string(),
+            priority: "0".to_string(),
+        }
+    }
+}
+
+impl SimChannel {
+    fn is_programmed(&self) -> bool {
+        self.frequency != "00000000"
+    }
+}
+
+/// An emulated BC125AT / UBC125XLT.
+///
+/// Holds the state the real radio keeps (channel memory, scan bank mask,
+/// volume and squelch) and answers commands in the formats documented in
+/// SCANNER-COMMANDS.md. [`Simulator::tick`] drives fake scan activity.
+pub struct Simulator {
+    channels: Vec<SimChannel>,
+    // SCG mask: '0' = bank scanned, '1' = bank locked out
+    scan_mask: String,
+    volume: u8,
+    squelch: u8,
+    // BLT, BSV, KBP, PRI, CNT and WXS values, keyed by command
+    settings: HashMap<String, String>,
+    // Global lockouts, in the 8-digit form
+    lockouts: Vec<String>,
+    // The next lockout GLF returns
+    glf_next: usize,
+    // CSP limits, in units of 100 Hz
+    search_ranges: Vec<(u32, u32)>,
+    // CSG mask: '0' = range searched, '1' = range skipped
+    search_mask: String,
+    // SSG mask: '0' = group searched, '1' = group skipped
+    service_mask: String,
+    // Searching rather than scanning channels
+    searching: bool,
+    // The search is a service search rather than a custom one
+    service_search: bool,
+    search_frequency: u32,
+    // Func was the last key pressed
+    func: bool,
+    // CLC values after the command name
+    close_call: String,
+    // Remaining ticks of the current Close Call hit
+    close_call_ticks: u32,
+    close_call_frequency: u32,
+    program_mode: bool,
+    holding: bool,
+    // A frequency typed on the keypad while holding, then tuned with Hold
+    entry: String,
+    tuned: Option<u32>,
+    current: usize,
+    // Remaining ticks with squelch open on the current channel
+    signal_ticks: u32,
+    rng: u64,
+}
+
+impl Default for Simulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Simulator {
+    pub fn new() -> Self {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0x2545_f491_4f6c_dd1d);
+
+        let mut sim = Self {
+            channels: vec![SimChannel::default(); CHANNEL_COUNT],
+            scan_mask: "0000000000".to_string(),
+            volume: 6,
+            squelch: 3,
+            settings: [
+                ("BLT", "KY"),
+                ("BSV", "14"),
+                ("KBP", "0,0"),
+                ("PRI", "0"),
+                ("CNT", "8"),
+                ("WXS", "0"),
+            ]
+            .into_iter()
+            .map(|(cmd, value)| (cmd.to_string(), value.to_string()))
+            .collect(),
+            lockouts: Vec::new(),
+            glf_next: 0,
+            search_ranges: SEARCH_RANGES
+                .iter()
+                .map(|(lower, upper)| (lower.parse().unwrap_or(0), upper.parse().unwrap_or(0)))
+                .collect(),
+            search_mask: "0000000000".to_string(),
+            service_mask: "0000000000".to_string(),
+            searching: false,
+            service_search: false,
+            search_frequency: 0,
+            func: false,
+            close_call: "0,1,1,11111,0".to_string(),
+            close_call_ticks: 0,
+            close_call_frequency: 0,
+            program_mode: false,
+            holding: false,
+            entry: String::new(),
+            tuned: None,
+            current: 0,
+            signal_ticks: 0,
+            rng: seed | 1,
+        };
+
+        // A few channels so there is something to scan out of the box
+        let samples = [
+            (1, "BHX APPR", "01189100", "AM"),
+            (2, "BHX TOWER", "01183000", "AM"),
+            (3, "BHX RADAR", "01239750", "AM"),
+            (4, "EGBB ATIS", "01360250", "AM"),
+            (51, "MARINE 16", "01568000", "FM"),
+            (52, "MARINE 6", "01563000", "FM"),
+            (53, "PMR 1", "04460062", "NFM"),
+            (101, "2M CALL", "01455000", "FM"),
+            (102, "70CM CALL", "04335000", "FM"),
+        ];
+        for (index, name, frequency, modulation) in samples {
+            let chan = &mut sim.channels[index - 1];
+            chan.name = name.to_string();
+            chan.frequency = frequency.to_string();
+            chan.modulation = modulation.to_string();
+        }
+        sim
+    }
+
+    /// Handles one command line (without the `\r`) and returns the response line.
+    pub fn handle(&mut self, line: &str) -> String {
+        let parts: Vec<&str> = line.trim().split(',').collect();
+        let cmd = parts[0];
+        let args = &parts[1..];
+
+        match cmd {
+            "MDL" if args.is_empty() => "MDL,BC125AT".to_string(),
+            "VER" if args.is_empty() => "VER,Version 1.06.06".to_string(),
+            "PRG" if args.is_empty() => {
+                self.program_mode = true;
+                self.signal_ticks = 0;
+                self.close_call_ticks = 0;
+                self.glf_next = 0;
+                "PRG,OK".to_string()
+            }
+            "EPG" if args.is_empty() => {
+                if !self.program_mode {
+                    return "NG".to_string();
+                }
+                self.program_mode = false;
+                // The radio returns to Scan Hold Mode
+                self.holding = true;
+                "EPG,OK".to_string()
+            }
+            "VOL" => self.level(cmd, args, |s| &mut s.volume),
+            "SQL" => self.level(cmd, args, |s| &mut s.squelch),
+            "GLG" if args.is_empty() => self.glg(),
+            "STS" if args.is_empty() => self.sts(),
+            "KEY" => self.key(args),
+            "SCG" | "CIN" | "DCH" | "CLR" | "BLT" | "BSV" | "KBP" | "PRI" | "CNT" | "WXS"
+            | "GLF" | "LOF" | "ULF" | "CSP" | "CSG" | "CLC" | "SSG"
+                if !self.program_mode =>
+            {
+                "NG".to_string()
+            }
+            "BLT" | "BSV" | "KBP" | "PRI" | "CNT" | "WXS" => self.setting(cmd, args),
+            "SCG" => Self::mask(cmd, args, &mut self.scan_mask),
+            "CSG" => Self::mask(cmd, args, &mut self.search_mask),
+            "SSG" => Self::mask(cmd, args, &mut self.service_mask),
+            "CSP" => self.csp(args),
+            "CLC" => self.clc(args),
+            "CIN" => self.cin(args),
+            "DCH" => self.dch(args),
+            "GLF" => match self.lockouts.get(self.glf_next) {
+                Some(frequency) => {
+                    self.glf_next += 1;
+                    format!("GLF,{}", frequency)
+                }
+                None => {
+                    self.glf_next = 0;
+                    "GLF,-1".to_string()
+                }
+            },
+            "LOF" | "ULF" => self.lockout(cmd, args),
+            "CLR" if args.is_empty() => {
+                self.channels = vec![SimChannel::default(); CHANNEL_COUNT];
+                self.scan_mask = "0000000000".to_string();
+                "CLR,OK".to_string()
+            }
+            _ => "ERR".to_string(),
+        }
+    }
+
+    /// Advances the fake scan by one step.
+    ///
+    /// While scanning, each tick moves to the next programmed, unlocked
+    /// channel in an enabled bank and occasionally opens the squelch on it
+    /// for a while, like a real transmission. With Close Call on, a
+    /// transmission on one of its bands is occasionally found too.
+    pub fn tick(&mut self) {
+        if self.program_mode || self.holding {
+            return;
+        }
+        if self.close_call_ticks > 0 {
+            self.close_call_ticks -= 1;
+            return;
+        }
+        if self.signal_ticks > 0 {
+            self.signal_ticks -= 1;
+            return;
+        }
+        if !self.searching
+            && !self.close_call.starts_with('0')
+            && self.random().is_multiple_of(100)
+            && let Some(frequency) = self.close_call_band_frequency()
+        {
+            self.close_call_frequency = frequency;
+            self.close_call_ticks = 10 + (self.random() % 20) as u32;
+            return;
+        }
+        if self.searching {
+            self.search_frequency = self.next_search_frequency();
+            if self.random().is_multiple_of(200) {
+                self.signal_ticks = 10 + (self.random() % 40) as u32;
+            }
+            return;
+        }
+        if let Some(next) = self.next_scan_channel() {
+            self.current = next;
+            if self.random().is_multiple_of(40) {
+                self.signal_ticks = 10 + (self.random() % 40) as u32;
+            }
+        }
+    }
+
+    fn next_scan_channel(&self) -> Option<usize> {
+        (1..=CHANNEL_COUNT)
+            .map(|offset| (self.current + offset) % CHANNEL_COUNT)
+            .find(|&i| {
+                let chan = &self.channels[i];
+                let bank = i / CHANNELS_PER_BANK;
+                chan.is_programmed()
+                    && chan.lockout == "0"
+                    && !self.lockouts.contains(&chan.frequency)
+                    && self.scan_mask.as_bytes()[bank] == b'0'
+            })
+    }
+
+    /// The next step through the enabled search ranges, wrapping from the
+    /// top of one to the bottom of the next. A service search steps through
+    /// the enabled groups' frequencies instead.
+    fn next_search_frequency(&self) -> u32 {
+        if self.service_search {
+            let enabled: Vec<u32> = SERVICE_FREQUENCIES
+                .iter()
+                .zip(self.service_mask.chars())
+                .filter(|(_, c)| *c == '0')
+                .flat_map(|(frequencies, _)| frequencies.iter().copied())
+                .collect();
+            let next = enabled
+                .iter()
+                .position(|&f| f == self.search_frequency)
+                .map_or(0, |i| (i + 1) % enabled.len());
+            return enabled.get(next).copied().unwrap_or(0);
+        }
+        let enabled: Vec<(u32, u32)> = self
+            .search_ranges
+            .iter()
+            .zip(self.search_mask.chars())
+            .filter(|(_, c)| *c == '0')
+            .map(|(range, _)| *range)
+            .collect();
+        let next = self.search_frequency + SEARCH_STEP;
+        let current = enabled
+            .iter()
+            .position(|&(lower, upper)| (lower..=upper).contains(&self.search_frequency));
+        match current {
+            Some(i) if next <= enabled[i].1 => next,
+            Some(i) => enabled[(i + 1) % enabled.len()].0,
+            None => enabled.first().map_or(0, |&(lower, _)| lower),
+        }
+    }
+
+    /// A Close Call frequency in a random enabled band.
+    fn close_call_band_frequency(&mut self) -> Option<u32> {
+        let bands = self.close_call.split(',').nth(3).unwrap_or("");
+        let enabled: Vec<u32> = CLOSE_CALL_FREQUENCIES
+            .iter()
+            .zip(bands.chars())
+            .filter(|(_, c)| *c == '1')
+            .map(|(frequency, _)| *frequency)
+            .collect();
+        if enabled.is_empty() {
+            return None;
+        }
+        Some(enabled[self.random() as usize % enabled.len()])
+    }
+
+    fn random(&mut self) -> u64 {
+        // xorshift64
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+        self.rng
+    }
+
+    fn level(&mut self, cmd: &str, args: &[&str], field: fn(&mut Self) -> &mut u8) -> String {
+        match args {
+            [] => format!("{},{}", cmd, field(self)),
+            [level] => match level.parse::<u8>() {
+                Ok(level) if level <= 15 => {
+                    *field(self) = level;
+                    format!("{},OK", cmd)
+                }
+                _ => "ERR".to_string(),
+            },
+            _ => "ERR".to_string(),
+        }
+    }
+
+    fn lockout(&mut self, cmd: &str, args: &[&str]) -> String {
+        let [frequency] = args else {
+            return "ERR".to_string();
+        };
+        if frequency.len() != 8 || !frequency.bytes().all(|b| b.is_ascii_digit()) {
+            return "ERR".to_string();
+        }
+        let pos = self.lockouts.iter().position(|f| f == frequency);
+        match (cmd, pos) {
+            ("LOF", None) => self.lockouts.push(frequency.to_string()),
+            ("ULF", Some(pos)) => {
+                self.lockouts.remove(pos);
+            }
+            _ => {}
+        }
+        format!("{},OK", cmd)
+    }
+
+    fn setting(&mut self, cmd: &str, args: &[&str]) -> String {
+        let in_range = |arg: &str, min: u8, max: u8| {
+            arg.parse::<u8>().is_ok_and(|v| (min..=max).contains(&v))
+        };
+        let valid = match (cmd, args) {
+            (_, []) => return format!("{},{}", cmd, self.settings[cmd]),
+            ("BLT", [event]) => matches!(*event, "AO" | "AF" | "KY" | "KS"),
+            ("BSV", [hours]) => in_range(hours, 1, 16),
+            ("KBP", [level, lock]) => matches!(*level, "0" | "99") && matches!(*lock, "0" | "1"),
+            ("PRI", [mode]) => in_range(mode, 0, 3),
+            ("CNT", [level]) => in_range(level, 1, 15),
+            ("WXS", [priority]) => matches!(*priority, "0" | "1"),
+            _ => false,
+        };
+        if !valid {
+            return "ERR".to_string();
+        }
+        self.settings.insert(cmd.to_string(), args.join(","));
+        format!("{},OK", cmd)
+    }
+
+    fn glg(&self) -> String {
+        let signal = self.signal_ticks > 0;
+        if self.close_call_ticks > 0 && !self.program_mode {
+            // Like a search hit, a Close Call hit has no channel
+            return format!(
+                "GLG,{:08},{},,0,,,,1,0,,,",
+                self.close_call_frequency,
+                Self::search_modulation(self.close_call_frequency)
+            );
+        }
+        if self.program_mode || (!signal && !self.holding) {
+            // Nothing to report while actively scanning
+            return "GLG,,,,,,,,,,,,".to_string();
+        }
+        if self.searching {
+            // A search hit has no channel
+            return format!(
+                "GLG,{:08},{},,0,,,,{},0,,,",
+                self.search_frequency,
+                Self::search_modulation(self.search_frequency),
+                if signal { 1 } else { 0 }
+            );
+        }
+        if let Some(frequency) = self.tuned {
+            return format!("GLG,{:08},{},,0,,,,0,0,,,", frequency, Self::search_modulation(frequency));
+        }
+        let chan = &self.channels[self.current];
+        if !chan.is_programmed() {
+            return "GLG,,,,,,,,,,,,".to_string();
+        }
+        format!(
+            "GLG,{},{},,0,,,{},{},0,,{},",
+            chan.frequency,
+            chan.modulation,
+            chan.name,
+            if signal { 1 } else { 0 },
+            self.current + 1
+        )
+    }
+
+    /// A simplified LCD: enabled banks on the first line, then the channel
+    /// being received or "SCAN" in the large font.
+    fn sts(&self) -> String {
+        let signal = self.signal_ticks > 0;
+        let chan = &self.channels[self.current];
+        let lines: [String; 4] = if self.program_mode {
+            [String::new(), "Remote Mode".to_string(), String::new(), String::new()]
+        } else {
+            let banks: String = self
+                .scan_mask
+                .chars()
+                .zip("1234567890".chars())
+                .map(|(locked, digit)| if locked == '0' { digit } else { '-' })
+                .collect();
+            let first = format!("Bank {}", banks);
+            if self.close_call_ticks > 0 {
+                let frequency = Frequency::from_hz(self.close_call_frequency * 100);
+                [
+                    "Close Call".to_string(),
+                    frequency.to_string(),
+                    Self::search_modulation(self.close_call_frequency).to_string(),
+                    String::new(),
+                ]
+            } else if let Some(tuned) = self.tuned {
+                let frequency = Frequency::from_hz(tuned * 100);
+                [
+                    first,
+                    frequency.to_string(),
+                    Self::search_modulation(tuned).to_string(),
+                    "HOLD".to_string(),
+                ]
+            } else if self.searching {
+                let frequency = Frequency::from_hz(self.search_frequency * 100);
+                [
+                    if self.service_search { "Service Search" } else { "Custom Search" }.to_string(),
+                    if self.holding { "SRCH HOLD" } else { "SEARCH" }.to_string(),
+                    format!("{} {}", frequency, Self::search_modulation(self.search_frequency)),
+                    String::new(),
+                ]
+            } else if (signal || self.holding) && chan.is_programmed() {
+                let frequency = Frequency::from_scanner(&chan.frequency)
+                    .map(|f| f.to_string())
+                    .unwrap_or_default();
+                [
+                    first,
+                    chan.name.clone(),
+                    format!("{} {}", frequency, chan.modulation),
+                    format!("CH{:03}{}", self.current + 1, if self.holding { "  HOLD" } else { "" }),
+                ]
+            } else {
+                [first, "SCAN".to_string(), String::new(), String::new()]
+            }
+        };
+
+        let mut response = "STS,0100".to_string();
+        for line in &lines {
+            let text: String = line.chars().take(LINE_WIDTH).collect();
+            response += &format!(",{:<width$},{:width$}", text, "", width = LINE_WIDTH);
+        }
+        let signal = signal || self.close_call_ticks > 0;
+        let level = if signal { 4 } else { 0 };
+        response += &format!(",{},0,0,,,{},,3", if signal { 1 } else { 0 }, level);
+        response
+    }
+
+    fn key(&mut self, args: &[&str]) -> String {
+        if self.program_mode {
+            return "NG".to_string();
+        }
+        let [key, mode] = args else {
+            return "ERR".to_string();
+        };
+        if !matches!(*mode, "P" | "L" | "H" | "R") {
+            return "ERR".to_string();
+        }
+        let func = std::mem::take(&mut self.func);
+        match *key {
+            "S" => {
+                self.holding = false;
+                self.tuned = None;
+                self.entry.clear();
+                self.searching = false;
+                self.signal_ticks = 0;
+                self.close_call_ticks = 0;
+            }
+            "R" => {
+                self.holding = false;
+                self.searching = true;
+                self.service_search = func;
+                self.signal_ticks = 0;
+                self.search_frequency = self.next_search_frequency();
+            }
+            "H" if self.holding && !self.entry.is_empty() => {
+                let entry = std::mem::take(&mut self.entry);
+                match entry.parse::<Frequency>() {
+                    Ok(frequency) => {
+                        self.tuned = Some(frequency.hz() / 100);
+                        self.searching = false;
+                    }
+                    Err(_) => return "ERR".to_string(),
+                }
+            }
+            "H" => {
+                self.holding = !self.holding;
+                self.tuned = None;
+            }
+            "F" => self.func = true,
+            k if self.holding && (k == "." || k.bytes().all(|b| b.is_ascii_digit())) => {
+                self.entry.push_str(k);
+            }
+            k if k.len() == 1 => {}
+            _ => return "ERR".to_string(),
+        }
+        "KEY,OK".to_string()
+    }
+
+    /// The radio picks a modulation for a search by band; AM for the civil
+    /// and military air bands.
+    fn search_modulation(frequency: u32) -> &'static str {
+        if (1_080_000..1_370_000).contains(&frequency) || (2_250_000..3_800_000).contains(&frequency) {
+            "AM"
+        } else {
+            "FM"
+        }
+    }
+
+    /// SCG and CSG, ten-digit masks where '0' means included.
+    fn mask(cmd: &str, args: &[&str], value: &mut String) -> String {
+        match args {
+            [] => format!("{},{}", cmd, value),
+            [mask] if mask.len() == 10 && mask.chars().all(|c| c == '0' || c == '1') => {
+                // The radio refuses to lock out everything
+                if !mask.contains('0') {
+                    return "ERR".to_string();
+                }
+                *value = mask.to_string();
+                format!("{},OK", cmd)
+            }
+            _ => "ERR".to_string(),
+        }
+    }
+
+    fn csp(&mut self, args: &[&str]) -> String {
+        let index = match args.first().and_then(|i| i.parse::<usize>().ok()) {
+            Some(i) if (1..=self.search_ranges.len()).contains(&i) => i - 1,
+            _ => return "ERR".to_string(),
+        };
+        let limit = |s: &str| {
+            (s.len() == 8 && s.bytes().all(|b| b.is_ascii_digit())).then(|| s.parse::<u32>().ok()).flatten()
+        };
+        match args {
+            [_] => {
+                let (lower, upper) = self.search_ranges[index];
+                format!("CSP,{},{:08},{:08}", index + 1, lower, upper)
+            }
+            [_, lower, upper] => match (limit(lower), limit(upper)) {
+                (Some(lower), Some(upper)) if lower <= upper => {
+                    self.search_ranges[index] = (lower, upper);
+                    "CSP,OK".to_string()
+                }
+                _ => "ERR".to_string(),
+            },
+            _ => "ERR".to_string(),
+        }
+    }
+
+    fn clc(&mut self, args: &[&str]) -> String {
+        let flag = |arg: &str| matches!(arg, "0" | "1");
+        match args {
+            [] => format!("CLC,{}", self.close_call),
+            [mode, beep, light, bands, lockout]
+                if matches!(*mode, "0" | "1" | "2")
+                    && flag(beep)
+                    && flag(light)
+                    && bands.len() == 5
+                    && bands.chars().all(|c| c == '0' || c == '1')
+                    && flag(lockout) =>
+            {
+                self.close_call = args.join(",");
+                "CLC,OK".to_string()
+            }
+            _ => "ERR".to_string(),
+        }
+    }
+
+    fn channel_index(arg: &str) -> Option<usize> {
+        match arg.parse::<usize>() {
+            Ok(i) if (1..=CHANNEL_COUNT).contains(&i) => Some(i - 1),
+            _ => None,
+        }
+    }
+
+    fn cin(&mut self, args: &[&str]) -> String {
+        match args {
+            [index] => match Self::channel_index(index) {
+                Some(i) => {
+                    let c = &self.channels[i];
+                    format!(
+                        "CIN,{},{},{},{},{},{},{},{}",
+                        i + 1,
+                        c.name,
+                        c.frequency,
+                        c.modulation,
+                        c.tone,
+                        c.delay,
+                        c.lockout,
+                        c.priority
+                    )
+                }
+                None => "ERR".to_string(),
+            },
+            [index, name, frequency, modulation, tone, delay, lockout, priority] => {
+                let Some(i) = Self::channel_index(index) else {
+                    return "ERR".to_string();
+                };
+                let valid = name.len() <= 16
+                    && frequency.len() == 8
+                    && frequency.chars().all(|c| c.is_ascii_digit())
+                    && MODULATIONS.contains(modulation)
+                    && tone.parse::<u8>().is_ok_and(|t| Tone::from_code(t).is_ok())
+                    && DELAYS.contains(delay)
+                    && matches!(*lockout, "0" | "1")
+                    && matches!(*priority, "0" | "1");
+                if !valid {
+                    return "ERR".to_string();
+                }
+                self.channels[i] = SimChannel {
+                    name: name.to_string(),
+                    frequency: frequency.to_string(),
+                    modulation: modulation.to_string(),
+                    tone: tone.to_string(),
+                    delay: delay.to_string(),
+                    lockout: lockout.to_string(),
+                    priority: priority.to_string(),
+                };
+                "CIN,OK".to_string()
+            }
+            _ => "ERR".to_string(),
+        }
+    }
+
+    fn dch(&mut self, args: &[&str]) -> String {
+        match args {
+            [index] => match Self::channel_index(index) {
+                Some(i) => {
+                    self.channels[i] = SimChannel::default();
+                    "DCH,OK".to_string()
+                }
+                None => "ERR".to_string(),
+            },
+            _ => "ERR".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_program_mode_commands() {
+        let mut sim = Simulator::new();
+        assert_eq!(sim.handle("CIN,1"), "NG");
+        assert_eq!(sim.handle("PRG"), "PRG,OK");
+        assert_eq!(sim.handle("CIN,3"), "CIN,3,BHX RADAR,01239750,AM,0,2,0,0");
+        assert_eq!(sim.handle("CIN,10,TEST,01455000,FM,0,2,0,0"), "CIN,OK");
+        assert_eq!(sim.handle("CIN,10"), "CIN,10,TEST,01455000,FM,0,2,0,0");
+        assert_eq!(sim.handle("CIN,10,TEST,0145500,FM,0,2,0,0"), "ERR");
+        assert_eq!(sim.handle("CIN,501"), "ERR");
+        assert_eq!(sim.handle("DCH,10"), "DCH,OK");
+        assert_eq!(sim.handle("CIN,10"), "CIN,10,,00000000,AUTO,0,2,0,0");
+        assert_eq!(sim.handle("SCG,1011111111"), "SCG,OK");
+        assert_eq!(sim.handle("SCG"), "SCG,1011111111");
+        assert_eq!(sim.handle("EPG"), "EPG,OK");
+        assert_eq!(sim.handle("EPG"), "NG");
+    }
+
+    #[test]
+    fn test_levels_and_errors() {
+        let mut sim = Simulator::new();
+        assert_eq!(sim.handle("VOL,9"), "VOL,OK");
+        assert_eq!(sim.handle("VOL"), "VOL,9");
+        assert_eq!(sim.handle("SQL,16"), "ERR");
+        assert_eq!(sim.handle("BOGUS"), "ERR");
+        assert_eq!(sim.handle("MDL"), "MDL,BC125AT");
+    }
+
+    #[test]
+    fn test_scan_only_visits_enabled_banks() {
+        let mut sim = Simulator::new();
+        sim.handle("PRG");
+        sim.handle("SCG,1011111111");
+        sim.handle("EPG");
+        sim.handle("KEY,S,P");
+        for _ in 0..200 {
+            sim.tick();
+            let bank = sim.current / CHANNELS_PER_BANK + 1;
+            assert_eq!(bank, 2);
+        }
+    }
+
+    #[test]
+    fn test_glg_while_holding() {
+        let mut sim = Simulator::new();
+        sim.current = 2;
+        sim.holding = true;
+        assert_eq!(sim.handle("GLG"), "GLG,01239750,AM,,0,,,BHX RADAR,0,0,,3,");
+        sim.holding = false;
+        assert_eq!(sim.handle("GLG"), "GLG,,,,,,,,,,,,");
+    }
+}